@@ -1,16 +1,73 @@
-use criterion::Criterion;
 use criterion::{criterion_group, criterion_main};
+use criterion::{Criterion, Throughput};
 use uuid::Uuid;
-use uuid_readable_rs::{generate, generate_from, generate_inverse, short, short_from};
+use uuid_readable_rs::{
+    generate, generate_from, generate_inverse, generate_inverse_many, generate_many, short,
+    short_from, write_long, write_short,
+};
 
 pub fn main_benches(c: &mut Criterion) {
-    c.bench_function("generate", |b| b.iter(|| generate()));
+    c.bench_function("generate", |b| b.iter(generate));
     c.bench_function("generate_inverse", |b| b.iter(|| generate_inverse("Jim Ruscio Rhianon the tease of Hooppole dared Codi Four Dysart and 5 frankly mussles")));
-    c.bench_function("short", |b| b.iter(|| short()));
+    c.bench_function("short", |b| b.iter(short));
     let uuid = Uuid::new_v4();
     c.bench_function("generate_from", |b| b.iter(|| generate_from(uuid)));
     c.bench_function("short_from", |b| b.iter(|| short_from(uuid)));
 }
 
-criterion_group!(benches, main_benches);
+/// Encoding into a new `String` versus writing into a buffer reused across
+/// calls, which doesn't allocate.
+pub fn write_benches(c: &mut Criterion) {
+    let uuid = Uuid::new_v4();
+    let mut buffer = String::with_capacity(128);
+    let mut group = c.benchmark_group("write");
+    group.bench_function("write_long", |b| {
+        b.iter(|| {
+            buffer.clear();
+            write_long(&uuid, &mut buffer)
+        })
+    });
+    group.bench_function("write_short", |b| {
+        b.iter(|| {
+            buffer.clear();
+            write_short(&uuid, &mut buffer)
+        })
+    });
+    group.finish();
+}
+
+/// Converting a million UUIDs to sentences and back, in parallel with the
+/// `rayon` feature.
+pub fn batch_benches(c: &mut Criterion) {
+    const ITEMS: usize = 1_000_000;
+    let uuids: Vec<Uuid> = (0..ITEMS).map(|_| Uuid::new_v4()).collect();
+    let sentences = generate_many(&uuids);
+
+    let mut group = c.benchmark_group("batch");
+    group.sample_size(10);
+    group.throughput(Throughput::Elements(ITEMS as u64));
+    group.bench_function("generate_many", |b| b.iter(|| generate_many(&uuids)));
+    group.bench_function("generate_inverse_many", |b| {
+        b.iter(|| generate_inverse_many(&sentences))
+    });
+    group.finish();
+}
+
+/// Decoding a sentence made of the first words of each list versus one made
+/// of the last words. With a linear scan of the lists the latter is far
+/// slower, with the reverse indexes both should take the same time.
+pub fn lookup_benches(c: &mut Criterion) {
+    let mut group = c.benchmark_group("generate_inverse_lookup");
+    group.bench_function("first_words", |b| b.iter(|| generate_inverse("Fusco Fusco Fusco the muleteer of Katy suspended Fusco Fusco Fusco and 0 mysterious rooks")));
+    group.bench_function("last_words", |b| b.iter(|| generate_inverse("Katharyn Katharyn Katharyn the minister of Mosinee trotted Katharyn Katharyn Katharyn and 31 jealous hogs")));
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    main_benches,
+    write_benches,
+    batch_benches,
+    lookup_benches
+);
 criterion_main!(benches);
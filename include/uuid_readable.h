@@ -0,0 +1,122 @@
+#ifndef UUID_READABLE_H
+#define UUID_READABLE_H
+
+/* Generated by cbindgen from src/ffi.rs, do not edit */
+
+#include <stddef.h>
+#include <stdint.h>
+
+/**
+ * Length of a buffer holding any long sentence, NUL included
+ */
+#define UUID_READABLE_LONG_LEN 165
+
+/**
+ * Length of a buffer holding any short sentence, NUL included
+ */
+#define UUID_READABLE_SHORT_LEN 58
+
+/**
+ * Status returned by the functions of the C API, the failures to decode a
+ * sentence mirroring the kinds of `ParseError`.
+ */
+typedef enum UuidReadableStatus {
+  /**
+   * Success
+   */
+  UUID_READABLE_STATUS_OK = 0,
+  /**
+   * A pointer given is NULL
+   */
+  UUID_READABLE_STATUS_NULL_POINTER,
+  /**
+   * The buffer is too small for the sentence and its NUL
+   */
+  UUID_READABLE_STATUS_BUFFER_TOO_SMALL,
+  /**
+   * The sentence isn't valid UTF-8
+   */
+  UUID_READABLE_STATUS_INVALID_UTF8,
+  /**
+   * The sentence doesn't have the expected number of words
+   */
+  UUID_READABLE_STATUS_WORD_COUNT,
+  /**
+   * A word isn't part of the list used for its position
+   */
+  UUID_READABLE_STATUS_UNKNOWN_WORD,
+  /**
+   * A word should have been a number
+   */
+  UUID_READABLE_STATUS_INVALID_NUMBER,
+  /**
+   * A number is too big to fit in the bits of its position
+   */
+  UUID_READABLE_STATUS_NUMBER_OUT_OF_RANGE,
+  /**
+   * A word linking the parts of the sentence isn't the expected one
+   */
+  UUID_READABLE_STATUS_CONNECTOR,
+  /**
+   * The padding is out of range or hides bytes that are not zero
+   */
+  UUID_READABLE_STATUS_PADDING,
+  /**
+   * The checksum doesn't match the rest of the sentence
+   */
+  UUID_READABLE_STATUS_CHECKSUM_MISMATCH,
+  /**
+   * Too many words are missing or wrong to rebuild the sentence
+   */
+  UUID_READABLE_STATUS_UNRECOVERABLE,
+  /**
+   * The words missing can be rebuilt in several ways, or a word only
+   * differs by its case from several ones of its list
+   */
+  UUID_READABLE_STATUS_AMBIGUOUS,
+} UuidReadableStatus;
+
+/**
+ * Derive the long sentence of the 16 bytes of `uuid`, written NUL
+ * terminated to the `len` bytes of `out`.
+ *
+ * # Safety
+ * `uuid` must point to 16 bytes and `out` to `len` writable bytes.
+ */
+enum UuidReadableStatus uuid_readable_generate_from(const uint8_t *uuid, char *out, size_t len);
+
+/**
+ * Get the 16 bytes of the UUID a long sentence was created from.
+ *
+ * # Safety
+ * `sentence` must be NUL terminated and `out` must point to 16 writable
+ * bytes.
+ */
+enum UuidReadableStatus uuid_readable_generate_inverse(const char *sentence, uint8_t *out);
+
+/**
+ * Derive the short sentence of the 16 bytes of `uuid`, written NUL
+ * terminated to the `len` bytes of `out`.
+ *
+ * # Safety
+ * `uuid` must point to 16 bytes and `out` to `len` writable bytes.
+ */
+enum UuidReadableStatus uuid_readable_short_from(const uint8_t *uuid, char *out, size_t len);
+
+/**
+ * Get the first 32 bits of the UUID a short sentence was created from.
+ *
+ * # Safety
+ * `sentence` must be NUL terminated and `out` must point to a writable
+ * `uint32_t`.
+ */
+enum UuidReadableStatus uuid_readable_short_inverse(const char *sentence, uint32_t *out);
+
+/**
+ * Describe a status, as a static NUL terminated string. The status is
+ * taken as an integer so that any value can be given, the ones that are
+ * not a `UuidReadableStatus` being described as an unknown status.
+ */
+const char *uuid_readable_status_message(uint32_t status);
+
+#endif  /* UUID_READABLE_H */
@@ -0,0 +1,231 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use uuid::Uuid;
+
+use crate::{Dictionary, ParseError};
+
+/// Derive the long sentence of each UUID, in order.
+///
+/// With the `rayon` feature the UUIDs are converted in parallel.
+///
+/// ```rust
+/// use uuid::Uuid;
+/// use uuid_readable_rs::{generate_inverse_many, generate_many};
+///
+/// let uuids = [Uuid::nil(), Uuid::max()];
+/// let sentences = generate_many(&uuids);
+/// assert_eq!(sentences[0], "Fusco Fusco Fusco the muleteer of Katy suspended Fusco Fusco Fusco and 0 mysterious rooks");
+///
+/// let back: Result<Vec<Uuid>, _> = generate_inverse_many(&sentences).into_iter().collect();
+/// assert_eq!(back.unwrap(), uuids);
+/// ```
+pub fn generate_many(uuids: &[Uuid]) -> Vec<String> {
+    _generate_many(Dictionary::builtin(), uuids)
+}
+
+/// Get the UUID of each long sentence, in order, as `generate_inverse` does.
+///
+/// With the `rayon` feature the sentences are converted in parallel.
+pub fn generate_inverse_many<S: AsRef<str> + Sync>(
+    sentences: &[S],
+) -> Vec<Result<Uuid, ParseError>> {
+    _generate_inverse_many(Dictionary::builtin(), sentences)
+}
+
+pub(crate) fn _generate_many(dict: &Dictionary, uuids: &[Uuid]) -> Vec<String> {
+    #[cfg(feature = "rayon")]
+    let uuids = uuids.par_iter();
+    #[cfg(not(feature = "rayon"))]
+    let uuids = uuids.iter();
+
+    uuids.map(|uuid| crate::_generate(dict, uuid)).collect()
+}
+
+pub(crate) fn _generate_inverse_many<S: AsRef<str> + Sync>(
+    dict: &Dictionary,
+    sentences: &[S],
+) -> Vec<Result<Uuid, ParseError>> {
+    #[cfg(feature = "rayon")]
+    let sentences = sentences.par_iter();
+    #[cfg(not(feature = "rayon"))]
+    let sentences = sentences.iter();
+
+    sentences
+        .map(|sentence| crate::_inverse(dict, sentence.as_ref(), false))
+        .collect()
+}
+
+/// Adapters converting the items of an iterator, see `sentences` and `uuids`.
+///
+/// ```rust
+/// use uuid::Uuid;
+/// use uuid_readable_rs::ReadableIterExt;
+///
+/// let lines = vec![Uuid::nil()].into_iter().sentences();
+/// for uuid in lines.uuids() {
+///     assert_eq!(uuid, Ok(Uuid::nil()));
+/// }
+/// ```
+pub trait ReadableIterExt: Iterator + Sized {
+    /// Turn each UUID into its long sentence
+    fn sentences(self) -> Sentences<Self>
+    where
+        Self::Item: Borrow<Uuid>,
+    {
+        Sentences { iter: self }
+    }
+
+    /// Turn each long sentence back into its UUID, as `generate_inverse` does
+    fn uuids(self) -> Uuids<Self>
+    where
+        Self::Item: AsRef<str>,
+    {
+        Uuids { iter: self }
+    }
+}
+
+impl<I: Iterator> ReadableIterExt for I {}
+
+/// Iterator over the long sentences of UUIDs, see `ReadableIterExt::sentences`.
+#[derive(Debug, Clone)]
+pub struct Sentences<I> {
+    iter: I,
+}
+
+impl<I> Iterator for Sentences<I>
+where
+    I: Iterator,
+    I::Item: Borrow<Uuid>,
+{
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        self.iter
+            .next()
+            .map(|uuid| crate::_generate(Dictionary::builtin(), uuid.borrow()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> DoubleEndedIterator for Sentences<I>
+where
+    I: DoubleEndedIterator,
+    I::Item: Borrow<Uuid>,
+{
+    fn next_back(&mut self) -> Option<String> {
+        self.iter
+            .next_back()
+            .map(|uuid| crate::_generate(Dictionary::builtin(), uuid.borrow()))
+    }
+}
+
+impl<I> ExactSizeIterator for Sentences<I>
+where
+    I: ExactSizeIterator,
+    I::Item: Borrow<Uuid>,
+{
+}
+
+/// Iterator over the UUIDs of long sentences, see `ReadableIterExt::uuids`.
+#[derive(Debug, Clone)]
+pub struct Uuids<I> {
+    iter: I,
+}
+
+impl<I> Iterator for Uuids<I>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+    type Item = Result<Uuid, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|sentence| crate::_inverse(Dictionary::builtin(), sentence.as_ref(), false))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> DoubleEndedIterator for Uuids<I>
+where
+    I: DoubleEndedIterator,
+    I::Item: AsRef<str>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next_back()
+            .map(|sentence| crate::_inverse(Dictionary::builtin(), sentence.as_ref(), false))
+    }
+}
+
+impl<I> ExactSizeIterator for Uuids<I>
+where
+    I: ExactSizeIterator,
+    I::Item: AsRef<str>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::{generate_from, generate_inverse};
+
+    fn uuids() -> Vec<Uuid> {
+        (0..1000u128)
+            .map(|i| Uuid::from_u128(i.wrapping_mul(0x9e37_79b9_7f4a_7c15_f39c_c060_5ced_c835)))
+            .collect()
+    }
+
+    #[test]
+    fn test_generate_many() {
+        let uuids = uuids();
+        let sentences = generate_many(&uuids);
+        assert_eq!(sentences.len(), uuids.len());
+        for (uuid, sentence) in uuids.iter().zip(&sentences) {
+            assert_eq!(*sentence, generate_from(*uuid));
+        }
+
+        let back = generate_inverse_many(&sentences);
+        assert_eq!(back, uuids.into_iter().map(Ok).collect::<Vec<_>>());
+
+        assert!(generate_many(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_generate_inverse_many_errors() {
+        let sentence = generate_from(Uuid::nil());
+        let results = generate_inverse_many(&[sentence.as_str(), "Purdy Fusco", &sentence]);
+        assert_eq!(results[0], Ok(Uuid::nil()));
+        assert_eq!(results[1], generate_inverse("Purdy Fusco"));
+        assert!(results[1].is_err());
+        assert_eq!(results[2], Ok(Uuid::nil()));
+    }
+
+    #[test]
+    fn test_iterators() {
+        let uuids = uuids();
+        let sentences: Vec<String> = uuids.iter().sentences().collect();
+        assert_eq!(sentences, generate_many(&uuids));
+
+        let mut back = sentences.iter().uuids();
+        assert_eq!(back.len(), uuids.len());
+        assert_eq!(back.next_back(), Some(Ok(uuids[uuids.len() - 1])));
+        assert_eq!(back.next(), Some(Ok(uuids[0])));
+
+        let results: Vec<_> = vec!["Purdy Fusco"].into_iter().uuids().collect();
+        assert!(results[0].is_err());
+    }
+}
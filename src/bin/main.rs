@@ -1,14 +1,194 @@
+use std::env;
+use std::io::{self, BufRead, BufWriter, Write};
+use std::process;
+
 use uuid::Uuid;
-use uuid_readable_rs::{generate, generate_from, generate_inverse, short};
+use uuid_readable_rs::filter::{decode_stream, encode_stream, Mode};
+use uuid_readable_rs::{
+    generate, generate_checked_from, generate_from, generate_inverse, generate_inverse_strict,
+    short, short_from,
+};
+
+const USAGE: &str = "Usage: uuid-readable <COMMAND> [ARGS]
+
+Commands:
+  encode [--checksum] [UUID]    Convert a UUID into its long sentence, followed
+                                by a checksum with --checksum
+  decode [--strict] [SENTENCE]  Convert a long sentence back into its UUID
+  short [UUID]                  Convert a UUID into its short sentence
+  random [--count N] [--short]  Generate sentences from random UUIDs
+  filter [--reverse] [--append] Rewrite the UUIDs found in stdin into sentences,
+                                or the sentences into UUIDs with --reverse
+  help                          Print this message
+
+Without UUID or SENTENCE, the input is read from stdin, one per line.
+
+Exit status: 0 on success, 1 if any input could not be converted, 2 on usage error.";
+
+/// Exit status when some input could not be converted
+const EXIT_FAILURE: i32 = 1;
+/// Exit status when the command line is invalid
+const EXIT_USAGE: i32 = 2;
 
 fn main() {
-    let uuid = Uuid::parse_str("08c60edc-1297-476c-a876-cef77a014757").unwrap();
-    let x = generate_from(uuid);
-    println!("Converted uuid = {}", x);
-    println!();
-    println!("Decoded uuid = {}", generate_inverse(&x).unwrap());
-    println!("Original uuid = {}\n", &uuid);
-
-    println!("Random full-length = {}", generate());
-    println!("Random short-length = {}", short());
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (command, args) = match args.split_first() {
+        Some((command, args)) => (command.as_str(), args),
+        None => usage_error("missing command"),
+    };
+
+    let stdout = io::stdout();
+    let mut out = BufWriter::new(stdout.lock());
+    let result = match command {
+        "encode" => {
+            let checksum = args.first().map(|a| a == "--checksum").unwrap_or(false);
+            let (args, f): (_, fn(Uuid) -> String) = if checksum {
+                (&args[1..], generate_checked_from)
+            } else {
+                (args, generate_from)
+            };
+            convert(&mut out, args, |line| encode(line, f))
+        }
+        "short" => convert(&mut out, args, |line| encode(line, short_from)),
+        "decode" => {
+            let strict = args.first().map(|a| a == "--strict").unwrap_or(false);
+            let args = if strict { &args[1..] } else { args };
+            // The sentence may be given as a single or several arguments
+            let args = if args.is_empty() {
+                Vec::new()
+            } else {
+                vec![args.join(" ")]
+            };
+            convert(&mut out, &args, |line| decode(line, strict))
+        }
+        "random" => random(&mut out, args),
+        "filter" => filter(&mut out, args),
+        "help" | "-h" | "--help" => {
+            println!("{}", USAGE);
+            Ok(true)
+        }
+        _ => usage_error(&format!("unknown command `{}`", command)),
+    };
+
+    match result.and_then(|ok| out.flush().map(|_| ok)) {
+        Ok(true) => {}
+        Ok(false) => process::exit(EXIT_FAILURE),
+        // The reader went away (`| head`), nothing left to do
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => {}
+        Err(e) => {
+            eprintln!("uuid-readable: {}", e);
+            process::exit(EXIT_FAILURE);
+        }
+    }
+}
+
+/// Print the error along with the usage and exit
+fn usage_error(msg: &str) -> ! {
+    eprintln!("uuid-readable: {}\n\n{}", msg, USAGE);
+    process::exit(EXIT_USAGE);
+}
+
+fn encode(line: &str, f: fn(Uuid) -> String) -> Result<String, String> {
+    Uuid::parse_str(line.trim())
+        .map(f)
+        .map_err(|e| format!("invalid UUID: {}", e))
+}
+
+fn decode(line: &str, strict: bool) -> Result<String, String> {
+    let uuid = if strict {
+        generate_inverse_strict(line)
+    } else {
+        generate_inverse(line)
+    };
+
+    uuid.map(|u| u.to_string())
+        .map_err(|e| format!("invalid sentence: {}", e))
+}
+
+/// Convert each argument, or each line of stdin if there are none, printing
+/// the errors on stderr. Return false if any of the conversions failed.
+fn convert<W, F>(out: &mut W, args: &[String], f: F) -> io::Result<bool>
+where
+    W: Write,
+    F: Fn(&str) -> Result<String, String>,
+{
+    let mut ok = true;
+    let mut handle = |out: &mut W, line: &str, lineno: Option<usize>| -> io::Result<()> {
+        match f(line) {
+            Ok(converted) => writeln!(out, "{}", converted),
+            Err(e) => {
+                ok = false;
+                match lineno {
+                    Some(n) => eprintln!("uuid-readable: line {}: {}", n, e),
+                    None => eprintln!("uuid-readable: {}", e),
+                }
+                Ok(())
+            }
+        }
+    };
+
+    if args.is_empty() {
+        let stdin = io::stdin();
+        for (i, line) in stdin.lock().lines().enumerate() {
+            let line = line?;
+            // Skip blank lines rather than reporting them
+            if !line.trim().is_empty() {
+                handle(out, &line, Some(i + 1))?;
+            }
+        }
+    } else {
+        for arg in args {
+            handle(out, arg, None)?;
+        }
+    }
+
+    Ok(ok)
+}
+
+fn random<W: Write>(out: &mut W, args: &[String]) -> io::Result<bool> {
+    let mut count = 1usize;
+    let mut is_short = false;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--short" => is_short = true,
+            "--count" | "-n" => {
+                count = match args.next().map(|n| n.parse()) {
+                    Some(Ok(n)) if n > 0 => n,
+                    _ => usage_error("--count expects a positive number"),
+                }
+            }
+            _ => usage_error(&format!("unexpected argument `{}`", arg)),
+        }
+    }
+
+    for _ in 0..count {
+        let sentence = if is_short { short() } else { generate() };
+        writeln!(out, "{}", sentence)?;
+    }
+
+    Ok(true)
+}
+
+fn filter<W: Write>(out: &mut W, args: &[String]) -> io::Result<bool> {
+    let mut reverse = false;
+    let mut mode = Mode::Replace;
+
+    for arg in args {
+        match arg.as_str() {
+            "--reverse" | "-r" => reverse = true,
+            "--append" | "-a" => mode = Mode::Append,
+            _ => usage_error(&format!("unexpected argument `{}`", arg)),
+        }
+    }
+
+    let stdin = io::stdin();
+    if reverse {
+        decode_stream(stdin.lock(), out, mode)?;
+    } else {
+        encode_stream(stdin.lock(), out, mode)?;
+    }
+
+    Ok(true)
 }
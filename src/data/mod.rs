@@ -1,8 +1,22 @@
 //! Lists of words from mainly <https://github.com/Debdut/uuid-readable>
 
+use crate::Category;
+
 pub mod adjectives;
 pub mod animals;
 pub mod names;
 pub mod personal_nouns;
 pub mod places;
 pub mod verbs;
+
+/// Get the list of words of a category
+pub fn list(category: Category) -> &'static [&'static str] {
+    match category {
+        Category::Name => names::NAMES,
+        Category::PersonalNoun => personal_nouns::PERSONAL_NOUNS,
+        Category::Place => places::PLACES,
+        Category::Verb => verbs::VERBS,
+        Category::Adjective => adjectives::ADJECTIVES,
+        Category::Animal => animals::ANIMALS,
+    }
+}
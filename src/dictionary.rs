@@ -0,0 +1,577 @@
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+use hashbrown::HashMap;
+use once_cell::race::OnceBox;
+
+use uuid::Uuid;
+
+use crate::{
+    data, Category, DictionaryError, EncodeError, FuzzyMatch, ParseError, Part, PartialMatch,
+    ReadableUuid, Template, COMPACT_LAYOUT, LONG_LAYOUT, MEDIUM_LAYOUT, SHORT_LAYOUT,
+};
+
+/// A list of words a part of the sentence is drawn from.
+///
+/// The word at index `i` encodes the value `i`, hence the order of the list
+/// matters and must never change once sentences have been shared.
+pub trait Wordlist: Send + Sync {
+    /// Number of words in the list
+    fn len(&self) -> usize;
+
+    /// Get the word at `index`, which must be lower than `len()`
+    fn word(&self, index: usize) -> &str;
+
+    /// Find the index of `word`, which must match exactly
+    fn position(&self, word: &str) -> Option<usize>;
+
+    /// Find the index of `word` ignoring its case if there is no exact match.
+    ///
+    /// The default implementation does a linear scan of the list.
+    fn position_any_case(&self, word: &str) -> Option<usize> {
+        self.position(word).or_else(|| {
+            let lowered = word.to_lowercase();
+            let mut found = (0..self.len()).filter(|&i| self.word(i).to_lowercase() == lowered);
+            // Words only differing by their case can't be told apart
+            match (found.next(), found.next()) {
+                (Some(i), None) => Some(i),
+                _ => None,
+            }
+        })
+    }
+
+    /// Count the words equal to `word` ignoring their case, more than one
+    /// meaning `position_any_case` can't tell them apart.
+    ///
+    /// The default implementation does a linear scan of the list.
+    fn count_any_case(&self, word: &str) -> usize {
+        let lowered = word.to_lowercase();
+        (0..self.len())
+            .filter(|&i| self.word(i).to_lowercase() == lowered)
+            .count()
+    }
+
+    /// True if the list holds no word
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A `Wordlist` backed by a `Vec`, with reverse indexes built on first use so
+/// that finding a word is done in constant time.
+///
+/// Without the `std` feature, threads racing to build an index may each build
+/// it, only one being kept.
+pub struct Words {
+    words: Vec<Cow<'static, str>>,
+    index: OnceBox<HashMap<Cow<'static, str>, usize>>,
+    folded: OnceBox<HashMap<String, (usize, usize)>>,
+}
+
+impl Words {
+    /// Create a list from words, static ones are not copied.
+    ///
+    /// ```rust
+    /// use uuid_readable_rs::{Words, Wordlist};
+    ///
+    /// let words = Words::new(vec!["alpha", "beta"]);
+    /// assert_eq!(words.position("beta"), Some(1));
+    /// ```
+    pub fn new<I, W>(words: I) -> Self
+    where
+        I: IntoIterator<Item = W>,
+        W: Into<Cow<'static, str>>,
+    {
+        Words {
+            words: words.into_iter().map(Into::into).collect(),
+            index: OnceBox::new(),
+            folded: OnceBox::new(),
+        }
+    }
+
+    fn index(&self) -> &HashMap<Cow<'static, str>, usize> {
+        self.index.get_or_init(|| {
+            let mut index = HashMap::with_capacity(self.words.len());
+            // Keep the first position of duplicated words
+            for (i, w) in self.words.iter().enumerate().rev() {
+                index.insert(w.clone(), i);
+            }
+            Box::new(index)
+        })
+    }
+
+    /// Lowercased reverse index, holding the first position of each word
+    /// and the number of words only differing by their case (`JoAnn` and
+    /// `Joann`), which can't be told apart.
+    fn folded(&self) -> &HashMap<String, (usize, usize)> {
+        self.folded.get_or_init(|| {
+            let mut index: HashMap<String, (usize, usize)> =
+                HashMap::with_capacity(self.words.len());
+            for (i, w) in self.words.iter().enumerate() {
+                index.entry(w.to_lowercase()).or_insert((i, 0)).1 += 1;
+            }
+            Box::new(index)
+        })
+    }
+}
+
+impl Wordlist for Words {
+    fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    fn word(&self, index: usize) -> &str {
+        &self.words[index]
+    }
+
+    fn position(&self, word: &str) -> Option<usize> {
+        self.index().get(word).copied()
+    }
+
+    fn position_any_case(&self, word: &str) -> Option<usize> {
+        self.position(word)
+            .or_else(|| match self.folded().get(&word.to_lowercase()) {
+                Some(&(i, 1)) => Some(i),
+                _ => None,
+            })
+    }
+
+    fn count_any_case(&self, word: &str) -> usize {
+        self.folded()
+            .get(&word.to_lowercase())
+            .map_or(0, |&(_, count)| count)
+    }
+}
+
+/// The set of lists used to build sentences, one per `Category`.
+///
+/// The default dictionary is made of the lists shipped with the crate and is
+/// the one used by `generate_from`, `generate_inverse` and friends. Some or all
+/// of the lists can be replaced to get your own vocabulary:
+///
+/// ```rust
+/// use uuid::Uuid;
+/// use uuid_readable_rs::{Category, Dictionary, Words};
+///
+/// // 128 animals are needed, as the long sentence uses 7 bits for them
+/// let animals: Vec<String> = (0..128).map(|i| format!("robots{}", i)).collect();
+/// let dictionary = Dictionary::builder()
+///     .list(Category::Animal, Words::new(animals))
+///     .build()
+///     .unwrap();
+///
+/// let sentence = dictionary.generate_from(Uuid::nil());
+/// assert!(sentence.ends_with("and 0 mysterious robots0"));
+/// assert_eq!(dictionary.generate_inverse(&sentence), Ok(Uuid::nil()));
+/// ```
+pub struct Dictionary {
+    lists: [Box<dyn Wordlist>; 6],
+}
+
+impl Dictionary {
+    /// Start building a dictionary from the default lists
+    pub fn builder() -> DictionaryBuilder {
+        DictionaryBuilder::default()
+    }
+
+    /// The dictionary made of the lists shipped with the crate
+    pub fn builtin() -> &'static Dictionary {
+        static BUILTIN: OnceBox<Dictionary> = OnceBox::new();
+        BUILTIN.get_or_init(|| Box::new(Dictionary::default()))
+    }
+
+    /// Get the list used for a category
+    pub fn list(&self, category: Category) -> &dyn Wordlist {
+        self.lists[category as usize].as_ref()
+    }
+
+    /// Number of bits a list must be able to hold, that is the largest slot
+    /// using the category in any of the builtin sentences.
+    pub fn required_bits(category: Category) -> u8 {
+        LONG_LAYOUT
+            .iter()
+            .chain(COMPACT_LAYOUT.iter())
+            .chain(MEDIUM_LAYOUT.iter())
+            .chain(SHORT_LAYOUT.iter())
+            .filter_map(|part| match part {
+                Part::Word { category: c, bits } if *c == category => Some(*bits),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Layout of the long sentence, see `generate_from`
+    pub fn long_template(&self) -> Template<'_> {
+        Template::builtin(LONG_LAYOUT, self)
+    }
+
+    /// Layout of the compact sentence, see `generate_compact_from`
+    pub fn compact_template(&self) -> Template<'_> {
+        Template::builtin(COMPACT_LAYOUT, self)
+    }
+
+    /// Layout of the medium sentence, see `medium_from`
+    pub fn medium_template(&self) -> Template<'_> {
+        Template::builtin(MEDIUM_LAYOUT, self)
+    }
+
+    /// Layout of the short sentence, see `short_from`
+    pub fn short_template(&self) -> Template<'_> {
+        Template::builtin(SHORT_LAYOUT, self)
+    }
+
+    /// Derive a long sentence from a UUID, see `generate_from`
+    pub fn generate_from<U: Into<ReadableUuid>>(&self, uuid: U) -> String {
+        crate::_generate(self, uuid.into().as_uuid())
+    }
+
+    /// Derive the long sentence of each UUID, see `generate_many`
+    pub fn generate_many(&self, uuids: &[Uuid]) -> Vec<String> {
+        crate::batch::_generate_many(self, uuids)
+    }
+
+    /// Get the UUID of each long sentence, see `generate_inverse_many`
+    pub fn generate_inverse_many<S: AsRef<str> + Sync>(
+        &self,
+        sentences: &[S],
+    ) -> Vec<Result<Uuid, ParseError>> {
+        crate::batch::_generate_inverse_many(self, sentences)
+    }
+
+    /// Write the long sentence of a UUID to `out`, see `write_long`
+    pub fn write_long<W: fmt::Write>(&self, uuid: &Uuid, out: &mut W) -> fmt::Result {
+        crate::_write_long(self, uuid, out)
+    }
+
+    /// Derive a long sentence followed by a checksum, see `generate_checked_from`
+    pub fn generate_checked_from<U: Into<ReadableUuid>>(&self, uuid: U) -> String {
+        crate::_generate_checked(self, uuid.into().as_uuid())
+    }
+
+    /// Derive a long sentence followed by its parity, see `generate_recoverable_from`
+    pub fn generate_recoverable_from<U: Into<ReadableUuid>>(&self, uuid: U) -> String {
+        crate::recover::_generate_recoverable(self, uuid.into().as_uuid())
+    }
+
+    /// Get the original uuid from a sentence missing some words, see `recover_inverse`
+    pub fn recover_inverse<S: AsRef<str>>(&self, sentence: S) -> Result<Uuid, ParseError> {
+        crate::recover::_recover_inverse(self, sentence.as_ref())
+    }
+
+    /// Get the original uuid from a sentence, see `generate_inverse`
+    pub fn generate_inverse<S: AsRef<str>>(&self, sentence: S) -> Result<Uuid, ParseError> {
+        crate::_inverse(self, sentence.as_ref(), false)
+    }
+
+    /// Get the original uuid from a sentence which may contain typos, see
+    /// `generate_inverse_fuzzy`
+    pub fn generate_inverse_fuzzy<S: AsRef<str>>(
+        &self,
+        sentence: S,
+    ) -> Result<FuzzyMatch, ParseError> {
+        crate::fuzzy::_inverse_fuzzy(self, sentence.as_ref())
+    }
+
+    /// Get the original uuid from an exact sentence, see `generate_inverse_strict`
+    pub fn generate_inverse_strict<S: AsRef<str>>(&self, sentence: S) -> Result<Uuid, ParseError> {
+        crate::_inverse(self, sentence.as_ref(), true)
+    }
+
+    /// Derive a compact sentence from a v4 UUID, see `generate_compact_from`
+    pub fn generate_compact_from<U: Into<ReadableUuid>>(
+        &self,
+        uuid: U,
+    ) -> Result<String, EncodeError> {
+        crate::_compact(self, uuid.into().as_uuid())
+    }
+
+    /// Get the original v4 UUID from a compact sentence, see `generate_compact_inverse`
+    pub fn generate_compact_inverse<S: AsRef<str>>(&self, sentence: S) -> Result<Uuid, ParseError> {
+        crate::_compact_inverse(self, sentence.as_ref())
+    }
+
+    /// Derive a medium sentence from a 64-bit id, see `medium_from`
+    pub fn medium_from(&self, id: u64) -> String {
+        crate::_medium(self, id)
+    }
+
+    /// Get the original id from a medium sentence, see `medium_inverse`
+    pub fn medium_inverse<S: AsRef<str>>(&self, sentence: S) -> Result<u64, ParseError> {
+        crate::_medium_inverse(self, sentence.as_ref())
+    }
+
+    /// Create a sentence from any bytes, see `encode_bytes`
+    pub fn encode_bytes<B: AsRef<[u8]>>(&self, bytes: B) -> String {
+        crate::_encode_bytes(self, bytes.as_ref())
+    }
+
+    /// Get the original bytes from a sentence, see `decode_bytes`
+    pub fn decode_bytes<S: AsRef<str>>(&self, sentence: S) -> Result<Vec<u8>, ParseError> {
+        crate::_decode_bytes(self, sentence.as_ref())
+    }
+
+    /// Get the UUIDs consistent with a partial sentence, see `generate_inverse_partial`
+    pub fn generate_inverse_partial<S: AsRef<str>>(
+        &self,
+        sentence: S,
+    ) -> Result<PartialMatch, ParseError> {
+        crate::partial::_inverse_partial(self, sentence.as_ref())
+    }
+
+    /// Derive a short sentence from a UUID, see `short_from`
+    pub fn short_from<U: Into<ReadableUuid>>(&self, uuid: U) -> String {
+        crate::_short(self, uuid.into().as_uuid())
+    }
+
+    /// Write the short sentence of a UUID to `out`, see `write_short`
+    pub fn write_short<W: fmt::Write>(&self, uuid: &Uuid, out: &mut W) -> fmt::Result {
+        crate::_write_short(self, uuid, out)
+    }
+
+    /// Get the first 32 bits of the original uuid, see `short_inverse`
+    pub fn short_inverse<S: AsRef<str>>(&self, sentence: S) -> Result<u32, ParseError> {
+        crate::_short_inverse(self, sentence.as_ref())
+    }
+
+    /// Find the position of a word in the list of a category
+    #[inline]
+    pub(crate) fn lookup(&self, category: Category, word: &str) -> Option<usize> {
+        self.list(category).position(word)
+    }
+
+    /// Same as `lookup`, ignoring the case of the word if there is no exact match.
+    #[inline]
+    pub(crate) fn lookup_any_case(&self, category: Category, word: &str) -> Option<usize> {
+        self.list(category).position_any_case(word)
+    }
+
+    /// Error for a word `lookup_any_case` didn't find at `position`, telling
+    /// apart the words matching several ones of the list by their case.
+    pub(crate) fn unknown_word(
+        &self,
+        category: Category,
+        word: &str,
+        position: usize,
+    ) -> ParseError {
+        match self.list(category).count_any_case(word) {
+            candidates if candidates > 1 => ParseError::Ambiguous { candidates },
+            _ => ParseError::UnknownWord { position, category },
+        }
+    }
+
+    /// Get the word at `index` in the list of a category
+    #[inline]
+    pub(crate) fn word(&self, category: Category, index: usize) -> &str {
+        self.list(category).word(index)
+    }
+}
+
+impl Default for Dictionary {
+    fn default() -> Self {
+        Dictionary {
+            lists: Category::ALL.map(builtin_list),
+        }
+    }
+}
+
+fn builtin_list(category: Category) -> Box<dyn Wordlist> {
+    Box::new(Words::new(data::list(category).iter().copied()))
+}
+
+/// Builder of a `Dictionary`, the lists not given are the default ones.
+#[derive(Default)]
+pub struct DictionaryBuilder {
+    lists: [Option<Box<dyn Wordlist>>; 6],
+}
+
+impl DictionaryBuilder {
+    /// Use `list` for the words of `category`
+    pub fn list<W: Wordlist + 'static>(mut self, category: Category, list: W) -> Self {
+        self.lists[category as usize] = Some(Box::new(list));
+        self
+    }
+
+    /// Check the lists and build the dictionary.
+    ///
+    /// Each list must hold at least `2**Dictionary::required_bits(category)`
+    /// words, which must be unique, not contain any whitespace and not start
+    /// or end with punctuation, which is dropped when decoding.
+    pub fn build(self) -> Result<Dictionary, DictionaryError> {
+        let mut lists = self.lists;
+        for category in Category::ALL.iter() {
+            match &lists[*category as usize] {
+                Some(list) => check(*category, list.as_ref())?,
+                None => lists[*category as usize] = Some(builtin_list(*category)),
+            }
+        }
+
+        Ok(Dictionary {
+            lists: lists.map(|l| l.expect("every list is set")),
+        })
+    }
+}
+
+/// Check the invariants a list must hold to be used for a category
+fn check(category: Category, list: &dyn Wordlist) -> Result<(), DictionaryError> {
+    let required = 1usize << Dictionary::required_bits(category);
+    if list.len() < required {
+        return Err(DictionaryError::TooFewWords {
+            category,
+            required,
+            found: list.len(),
+        });
+    }
+
+    for i in 0..list.len() {
+        let word = list.word(i);
+        if word.is_empty()
+            || word.contains(char::is_whitespace)
+            || word.starts_with(crate::is_punctuation)
+            || word.ends_with(crate::is_punctuation)
+        {
+            return Err(DictionaryError::InvalidWord {
+                category,
+                word: word.to_string(),
+            });
+        }
+        if list.position(word) != Some(i) {
+            return Err(DictionaryError::DuplicateWord {
+                category,
+                word: word.to_string(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{format, vec};
+
+    fn numbered(prefix: &str, count: usize) -> Words {
+        Words::new((0..count).map(|i| format!("{}{}", prefix, i)))
+    }
+
+    #[test]
+    fn test_builtin_sanity() {
+        for category in Category::ALL.iter() {
+            let list = Dictionary::builtin().list(*category);
+            assert!(check(*category, list).is_ok(), "{}", category);
+            assert_eq!(list.len(), data::list(*category).len());
+        }
+    }
+
+    #[test]
+    fn test_required_bits() {
+        assert_eq!(Dictionary::required_bits(Category::Name), 14);
+        assert_eq!(Dictionary::required_bits(Category::PersonalNoun), 13);
+        assert_eq!(Dictionary::required_bits(Category::Place), 13);
+        assert_eq!(Dictionary::required_bits(Category::Verb), 10);
+        // 6 bits in the long sentence but 8 in the short one
+        assert_eq!(Dictionary::required_bits(Category::Adjective), 8);
+        assert_eq!(Dictionary::required_bits(Category::Animal), 7);
+    }
+
+    #[test]
+    fn test_custom_dictionary() {
+        let dictionary = Dictionary::builder()
+            .list(Category::Verb, numbered("verb", 1024))
+            .list(Category::Animal, numbered("animal", 128))
+            .build()
+            .unwrap();
+
+        let uuid = Uuid::parse_str("0ee001c7-12f3-4b29-a4cc-f48838b3587a").unwrap();
+        let sentence = dictionary.generate_from(uuid);
+        assert_eq!(
+            sentence,
+            "Purdy Fusco Kask the loki of Manteo verb841 Barbe Lehet Pardew and 26 hard animal122"
+        );
+        assert_eq!(dictionary.generate_inverse(&sentence), Ok(uuid));
+        assert_eq!(dictionary.generate_inverse_strict(&sentence), Ok(uuid));
+        assert!(crate::generate_inverse(&sentence).is_err());
+
+        let short = dictionary.short_from(uuid);
+        assert_eq!(short, "Egidius verb46 by 0 calm animal7");
+        assert_eq!(dictionary.short_inverse(&short), Ok(0x0ee001c7));
+    }
+
+    #[test]
+    fn test_invalid_dictionary() {
+        let too_few = Dictionary::builder()
+            .list(Category::Animal, numbered("animal", 127))
+            .build();
+        assert!(matches!(
+            too_few,
+            Err(DictionaryError::TooFewWords {
+                category: Category::Animal,
+                required: 128,
+                found: 127
+            })
+        ));
+
+        let mut words: Vec<String> = (0..128).map(|i| format!("animal{}", i)).collect();
+        words[12] = "animal3".to_string();
+        let duplicated = Dictionary::builder()
+            .list(Category::Animal, Words::new(words))
+            .build();
+        assert!(matches!(
+            duplicated,
+            Err(DictionaryError::DuplicateWord { category: Category::Animal, ref word }) if word == "animal3"
+        ));
+
+        let mut words: Vec<String> = (0..128).map(|i| format!("animal{}", i)).collect();
+        words[5] = "polar bears".to_string();
+        let spaced = Dictionary::builder()
+            .list(Category::Animal, Words::new(words))
+            .build();
+        assert!(matches!(
+            spaced,
+            Err(DictionaryError::InvalidWord {
+                category: Category::Animal,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_punctuated_dictionary() {
+        // The punctuation around the words is dropped when decoding
+        for bad in ["robot0.", "(robot0", "-robot0-"].iter() {
+            let mut words: Vec<String> = (0..128).map(|i| format!("robot{}", i)).collect();
+            words[0] = bad.to_string();
+            let punctuated = Dictionary::builder()
+                .list(Category::Animal, Words::new(words))
+                .build();
+            assert!(matches!(
+                punctuated,
+                Err(DictionaryError::InvalidWord { category: Category::Animal, ref word }) if word == bad
+            ));
+        }
+
+        let words: Vec<String> = (0..128).map(|i| format!("ro-bot{}", i)).collect();
+        let dictionary = Dictionary::builder()
+            .list(Category::Animal, Words::new(words))
+            .build()
+            .unwrap();
+        let sentence = dictionary.generate_from(Uuid::nil());
+        assert!(sentence.ends_with(" ro-bot0"), "{}", sentence);
+        assert_eq!(dictionary.generate_inverse(&sentence), Ok(Uuid::nil()));
+    }
+
+    #[test]
+    fn test_words_any_case() {
+        let words = Words::new(vec!["JoAnn", "Joann", "Purdy"]);
+        assert_eq!(words.position_any_case("purdy"), Some(2));
+        assert_eq!(words.position_any_case("joann"), None);
+        assert_eq!(words.position_any_case("Joann"), Some(1));
+        assert_eq!(words.count_any_case("joann"), 2);
+        assert_eq!(words.count_any_case("PURDY"), 1);
+        assert_eq!(words.count_any_case("Fusco"), 0);
+    }
+}
@@ -0,0 +1,212 @@
+use alloc::string::String;
+use core::error::Error;
+use core::fmt;
+
+use uuid::Uuid;
+
+/// Kind of word expected at a given position of a sentence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Category {
+    Name,
+    PersonalNoun,
+    Place,
+    Verb,
+    Adjective,
+    Animal,
+}
+
+impl Category {
+    /// Every category, in the order of their discriminant
+    pub const ALL: [Category; 6] = [
+        Category::Name,
+        Category::PersonalNoun,
+        Category::Place,
+        Category::Verb,
+        Category::Adjective,
+        Category::Animal,
+    ];
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Category::Name => "name",
+            Category::PersonalNoun => "personal noun",
+            Category::Place => "place",
+            Category::Verb => "verb",
+            Category::Adjective => "adjective",
+            Category::Animal => "animal",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Error returned when a sentence can't be converted back.
+///
+/// Positions are the index of the word in the sentence, starting at 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// The sentence doesn't have the expected number of words
+    WordCount { expected: usize, found: usize },
+    /// The word isn't part of the list used for its position
+    UnknownWord { position: usize, category: Category },
+    /// The word should have been a number
+    InvalidNumber { position: usize },
+    /// The number is too big to fit in the bits of its position
+    NumberOutOfRange { position: usize, max: u16 },
+    /// The word linking the parts of the sentence isn't the expected one
+    Connector { position: usize, expected: String },
+    /// The padding is out of range or hides bytes that are not zero
+    Padding { position: usize },
+    /// The checksum doesn't match the rest of the sentence
+    ChecksumMismatch,
+    /// Too many words are missing or wrong to rebuild the sentence
+    Unrecoverable,
+    /// The words missing can be rebuilt in several ways, or a word only
+    /// differs by its case from several ones of its list
+    Ambiguous { candidates: usize },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::WordCount { expected, found } => {
+                write!(f, "expected {} words, found {}", expected, found)
+            }
+            ParseError::UnknownWord { position, category } => {
+                write!(f, "unknown {} at position {}", category, position)
+            }
+            ParseError::InvalidNumber { position } => {
+                write!(f, "invalid number at position {}", position)
+            }
+            ParseError::NumberOutOfRange { position, max } => {
+                write!(f, "number at position {} is greater than {}", position, max)
+            }
+            ParseError::Connector { position, expected } => {
+                write!(f, "expected \"{}\" at position {}", expected, position)
+            }
+            ParseError::Padding { position } => {
+                write!(f, "invalid padding at position {}", position)
+            }
+            ParseError::ChecksumMismatch => f.write_str("checksum mismatch"),
+            ParseError::Unrecoverable => f.write_str("too many words are missing or wrong"),
+            ParseError::Ambiguous { candidates } => {
+                write!(f, "the sentence matches {} UUIDs", candidates)
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Error returned when the lists given to a `Dictionary` can't be used.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DictionaryError {
+    /// The list doesn't have enough words for the bits of its slots
+    TooFewWords {
+        category: Category,
+        required: usize,
+        found: usize,
+    },
+    /// The word appears more than once in the list
+    DuplicateWord { category: Category, word: String },
+    /// The word is empty, contains whitespace or starts or ends with
+    /// punctuation
+    InvalidWord { category: Category, word: String },
+}
+
+impl fmt::Display for DictionaryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DictionaryError::TooFewWords {
+                category,
+                required,
+                found,
+            } => write!(
+                f,
+                "the {} list needs at least {} words, found {}",
+                category, required, found
+            ),
+            DictionaryError::DuplicateWord { category, word } => {
+                write!(f, "duplicated word \"{}\" in the {} list", word, category)
+            }
+            DictionaryError::InvalidWord { category, word } => {
+                write!(f, "invalid word \"{}\" in the {} list", word, category)
+            }
+        }
+    }
+}
+
+impl Error for DictionaryError {}
+
+/// Error returned when a sentence template can't be used.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TemplateError {
+    /// The slots don't hold the number of bits of the source
+    BitBudget { expected: u32, found: u32 },
+    /// The template holds more bits than the 128 of a UUID
+    TooManyBits(u32),
+    /// The slot doesn't name a known category
+    UnknownSlot(String),
+    /// The bits of the slot are missing or not between 1 and 16
+    InvalidBits(String),
+    /// The word is empty, contains a brace without being a slot, or is a
+    /// literal starting or ending with punctuation
+    InvalidWord(String),
+    /// The list of the category can't hold the bits of a slot
+    ListTooSmall {
+        category: Category,
+        bits: u8,
+        len: usize,
+    },
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TemplateError::BitBudget { expected, found } => {
+                write!(f, "the slots hold {} bits instead of {}", found, expected)
+            }
+            TemplateError::TooManyBits(bits) => {
+                write!(f, "the template holds {} bits, more than 128", bits)
+            }
+            TemplateError::UnknownSlot(slot) => write!(f, "unknown slot \"{}\"", slot),
+            TemplateError::InvalidBits(slot) => {
+                write!(f, "invalid number of bits in slot \"{}\"", slot)
+            }
+            TemplateError::InvalidWord(word) => write!(f, "invalid word \"{}\"", word),
+            TemplateError::ListTooSmall {
+                category,
+                bits,
+                len,
+            } => write!(
+                f,
+                "the {} list has {} words, too few for {} bits",
+                category, len, bits
+            ),
+        }
+    }
+}
+
+impl Error for TemplateError {}
+
+/// Error returned when a UUID can't be converted to a sentence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EncodeError {
+    /// The compact sentence only holds random (v4) UUIDs
+    NotV4(Uuid),
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodeError::NotV4(uuid) => write!(f, "{} is not a random (v4) UUID", uuid),
+        }
+    }
+}
+
+impl Error for EncodeError {}
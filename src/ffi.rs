@@ -0,0 +1,365 @@
+//! C API (requires the `ffi` feature), its header being
+//! `include/uuid_readable.h`, generated by cbindgen.
+//!
+//! ```c
+//! uint8_t uuid[16] = { 0x0e, 0xe0, 0x01, 0xc7, /* ... */ };
+//! char sentence[UUID_READABLE_LONG_LEN];
+//! if (uuid_readable_generate_from(uuid, sentence, sizeof(sentence)) != UUID_READABLE_STATUS_OK) {
+//!     /* ... */
+//! }
+//! ```
+//!
+//! The sentences are written NUL terminated into a buffer owned by the
+//! caller, nothing is allocated.
+
+use core::ffi::{c_char, CStr};
+use core::fmt::{self, Write};
+use core::slice;
+
+use uuid::Uuid;
+
+use crate::{generate_inverse, short_inverse, write_long, write_short, ParseError};
+
+/// Length of a buffer holding any long sentence, NUL included
+pub const UUID_READABLE_LONG_LEN: usize = 165;
+
+/// Length of a buffer holding any short sentence, NUL included
+pub const UUID_READABLE_SHORT_LEN: usize = 58;
+
+/// Status returned by the functions of the C API, the failures to decode a
+/// sentence mirroring the kinds of `ParseError`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UuidReadableStatus {
+    /// Success
+    Ok = 0,
+    /// A pointer given is NULL
+    NullPointer,
+    /// The buffer is too small for the sentence and its NUL
+    BufferTooSmall,
+    /// The sentence isn't valid UTF-8
+    InvalidUtf8,
+    /// The sentence doesn't have the expected number of words
+    WordCount,
+    /// A word isn't part of the list used for its position
+    UnknownWord,
+    /// A word should have been a number
+    InvalidNumber,
+    /// A number is too big to fit in the bits of its position
+    NumberOutOfRange,
+    /// A word linking the parts of the sentence isn't the expected one
+    Connector,
+    /// The padding is out of range or hides bytes that are not zero
+    Padding,
+    /// The checksum doesn't match the rest of the sentence
+    ChecksumMismatch,
+    /// Too many words are missing or wrong to rebuild the sentence
+    Unrecoverable,
+    /// The words missing can be rebuilt in several ways, or a word only
+    /// differs by its case from several ones of its list
+    Ambiguous,
+}
+
+impl From<ParseError> for UuidReadableStatus {
+    fn from(err: ParseError) -> Self {
+        match err {
+            ParseError::WordCount { .. } => UuidReadableStatus::WordCount,
+            ParseError::UnknownWord { .. } => UuidReadableStatus::UnknownWord,
+            ParseError::InvalidNumber { .. } => UuidReadableStatus::InvalidNumber,
+            ParseError::NumberOutOfRange { .. } => UuidReadableStatus::NumberOutOfRange,
+            ParseError::Connector { .. } => UuidReadableStatus::Connector,
+            ParseError::Padding { .. } => UuidReadableStatus::Padding,
+            ParseError::ChecksumMismatch => UuidReadableStatus::ChecksumMismatch,
+            ParseError::Unrecoverable => UuidReadableStatus::Unrecoverable,
+            ParseError::Ambiguous { .. } => UuidReadableStatus::Ambiguous,
+        }
+    }
+}
+
+/// Buffer of the caller, keeping room for the NUL
+struct Buffer<'a> {
+    bytes: &'a mut [u8],
+    len: usize,
+}
+
+impl Write for Buffer<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end >= self.bytes.len() {
+            return Err(fmt::Error);
+        }
+        self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Write a sentence NUL terminated to `out`, left empty on failure
+unsafe fn write_sentence<F>(
+    uuid: *const u8,
+    out: *mut c_char,
+    len: usize,
+    write: F,
+) -> UuidReadableStatus
+where
+    F: Fn(&Uuid, &mut Buffer) -> fmt::Result,
+{
+    if uuid.is_null() || out.is_null() {
+        return UuidReadableStatus::NullPointer;
+    }
+    if len == 0 {
+        return UuidReadableStatus::BufferTooSmall;
+    }
+
+    let mut bytes = [0; 16];
+    bytes.copy_from_slice(slice::from_raw_parts(uuid, 16));
+    let mut buffer = Buffer {
+        bytes: slice::from_raw_parts_mut(out.cast::<u8>(), len),
+        len: 0,
+    };
+    let status = match write(&Uuid::from_bytes(bytes), &mut buffer) {
+        Ok(()) => UuidReadableStatus::Ok,
+        Err(_) => {
+            buffer.len = 0;
+            UuidReadableStatus::BufferTooSmall
+        }
+    };
+    buffer.bytes[buffer.len] = 0;
+
+    status
+}
+
+/// Read a NUL terminated sentence
+unsafe fn read_sentence<'a>(sentence: *const c_char) -> Result<&'a str, UuidReadableStatus> {
+    if sentence.is_null() {
+        return Err(UuidReadableStatus::NullPointer);
+    }
+    CStr::from_ptr(sentence)
+        .to_str()
+        .map_err(|_| UuidReadableStatus::InvalidUtf8)
+}
+
+/// Derive the long sentence of the 16 bytes of `uuid`, written NUL
+/// terminated to the `len` bytes of `out`.
+///
+/// # Safety
+/// `uuid` must point to 16 bytes and `out` to `len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn uuid_readable_generate_from(
+    uuid: *const u8,
+    out: *mut c_char,
+    len: usize,
+) -> UuidReadableStatus {
+    write_sentence(uuid, out, len, |uuid, buffer| write_long(uuid, buffer))
+}
+
+/// Get the 16 bytes of the UUID a long sentence was created from.
+///
+/// # Safety
+/// `sentence` must be NUL terminated and `out` must point to 16 writable
+/// bytes.
+#[no_mangle]
+pub unsafe extern "C" fn uuid_readable_generate_inverse(
+    sentence: *const c_char,
+    out: *mut u8,
+) -> UuidReadableStatus {
+    if out.is_null() {
+        return UuidReadableStatus::NullPointer;
+    }
+    match read_sentence(sentence).map(|s| generate_inverse(s).map_err(UuidReadableStatus::from)) {
+        Ok(Ok(uuid)) => {
+            slice::from_raw_parts_mut(out, 16).copy_from_slice(uuid.as_bytes());
+            UuidReadableStatus::Ok
+        }
+        Ok(Err(status)) | Err(status) => status,
+    }
+}
+
+/// Derive the short sentence of the 16 bytes of `uuid`, written NUL
+/// terminated to the `len` bytes of `out`.
+///
+/// # Safety
+/// `uuid` must point to 16 bytes and `out` to `len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn uuid_readable_short_from(
+    uuid: *const u8,
+    out: *mut c_char,
+    len: usize,
+) -> UuidReadableStatus {
+    write_sentence(uuid, out, len, |uuid, buffer| write_short(uuid, buffer))
+}
+
+/// Get the first 32 bits of the UUID a short sentence was created from.
+///
+/// # Safety
+/// `sentence` must be NUL terminated and `out` must point to a writable
+/// `uint32_t`.
+#[no_mangle]
+pub unsafe extern "C" fn uuid_readable_short_inverse(
+    sentence: *const c_char,
+    out: *mut u32,
+) -> UuidReadableStatus {
+    if out.is_null() {
+        return UuidReadableStatus::NullPointer;
+    }
+    match read_sentence(sentence).map(|s| short_inverse(s).map_err(UuidReadableStatus::from)) {
+        Ok(Ok(prefix)) => {
+            *out = prefix;
+            UuidReadableStatus::Ok
+        }
+        Ok(Err(status)) | Err(status) => status,
+    }
+}
+
+/// Every status, to check the values given by C
+const STATUSES: [UuidReadableStatus; 13] = [
+    UuidReadableStatus::Ok,
+    UuidReadableStatus::NullPointer,
+    UuidReadableStatus::BufferTooSmall,
+    UuidReadableStatus::InvalidUtf8,
+    UuidReadableStatus::WordCount,
+    UuidReadableStatus::UnknownWord,
+    UuidReadableStatus::InvalidNumber,
+    UuidReadableStatus::NumberOutOfRange,
+    UuidReadableStatus::Connector,
+    UuidReadableStatus::Padding,
+    UuidReadableStatus::ChecksumMismatch,
+    UuidReadableStatus::Unrecoverable,
+    UuidReadableStatus::Ambiguous,
+];
+
+/// Describe a status, as a static NUL terminated string. The status is
+/// taken as an integer so that any value can be given, the ones that are
+/// not a `UuidReadableStatus` being described as an unknown status.
+#[no_mangle]
+pub extern "C" fn uuid_readable_status_message(status: u32) -> *const c_char {
+    let status = STATUSES.iter().find(|known| **known as u32 == status);
+    let message: &'static [u8] = match status {
+        Some(UuidReadableStatus::Ok) => b"ok\0",
+        Some(UuidReadableStatus::NullPointer) => b"null pointer\0",
+        Some(UuidReadableStatus::BufferTooSmall) => b"buffer too small\0",
+        Some(UuidReadableStatus::InvalidUtf8) => b"invalid UTF-8\0",
+        Some(UuidReadableStatus::WordCount) => b"wrong number of words\0",
+        Some(UuidReadableStatus::UnknownWord) => b"unknown word\0",
+        Some(UuidReadableStatus::InvalidNumber) => b"invalid number\0",
+        Some(UuidReadableStatus::NumberOutOfRange) => b"number out of range\0",
+        Some(UuidReadableStatus::Connector) => b"unexpected connecting word\0",
+        Some(UuidReadableStatus::Padding) => b"invalid padding\0",
+        Some(UuidReadableStatus::ChecksumMismatch) => b"checksum mismatch\0",
+        Some(UuidReadableStatus::Unrecoverable) => b"too many words are missing or wrong\0",
+        Some(UuidReadableStatus::Ambiguous) => b"the sentence matches several UUIDs\0",
+        None => b"unknown status\0",
+    };
+    message.as_ptr().cast()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::ffi::CString;
+    use alloc::string::ToString;
+    use core::ptr;
+
+    use super::*;
+    use crate::{Dictionary, Part, Template};
+
+    const UUID: [u8; 16] = [
+        0x0e, 0xe0, 0x01, 0xc7, 0x12, 0xf3, 0x4b, 0x29, 0xa4, 0xcc, 0xf4, 0x88, 0x38, 0xb3, 0x58,
+        0x7a,
+    ];
+    const SENTENCE: &str =
+        "Purdy Fusco Kask the loki of Manteo observed Barbe Lehet Pardew and 26 hard herons";
+
+    fn c_string(s: &str) -> CString {
+        CString::new(s).unwrap()
+    }
+
+    /// Length of the longest sentence of a template, NUL included
+    fn longest(template: Template) -> usize {
+        let dict = Dictionary::builtin();
+        let words = template.parts().iter().map(|part| match part {
+            Part::Literal(word) => word.len(),
+            Part::Word { category, bits } => (0..1usize << bits)
+                .map(|i| dict.word(*category, i).len())
+                .max()
+                .unwrap(),
+            Part::Number { bits } => ((1u32 << bits) - 1).to_string().len(),
+        });
+        words.sum::<usize>() + template.parts().len()
+    }
+
+    #[test]
+    fn test_buffer_lengths() {
+        let dict = Dictionary::builtin();
+        assert_eq!(longest(dict.long_template()), UUID_READABLE_LONG_LEN);
+        assert_eq!(longest(dict.short_template()), UUID_READABLE_SHORT_LEN);
+    }
+
+    #[test]
+    fn test_ffi_roundtrip() {
+        let mut out = [0 as c_char; UUID_READABLE_LONG_LEN];
+        let status =
+            unsafe { uuid_readable_generate_from(UUID.as_ptr(), out.as_mut_ptr(), out.len()) };
+        assert_eq!(status, UuidReadableStatus::Ok);
+        let sentence = unsafe { CStr::from_ptr(out.as_ptr()) };
+        assert_eq!(sentence.to_str(), Ok(SENTENCE));
+
+        let mut uuid = [0; 16];
+        let status =
+            unsafe { uuid_readable_generate_inverse(sentence.as_ptr(), uuid.as_mut_ptr()) };
+        assert_eq!(status, UuidReadableStatus::Ok);
+        assert_eq!(uuid, UUID);
+
+        let status =
+            unsafe { uuid_readable_short_from(UUID.as_ptr(), out.as_mut_ptr(), out.len()) };
+        assert_eq!(status, UuidReadableStatus::Ok);
+        let short = unsafe { CStr::from_ptr(out.as_ptr()) };
+        assert_eq!(short.to_str(), Ok("Egidius filled by 0 calm hawks"));
+
+        let mut prefix = 0;
+        let status = unsafe { uuid_readable_short_inverse(short.as_ptr(), &mut prefix) };
+        assert_eq!(status, UuidReadableStatus::Ok);
+        assert_eq!(prefix, 0x0ee001c7);
+    }
+
+    #[test]
+    fn test_ffi_errors() {
+        // One byte short for the NUL
+        let mut out = [1 as c_char; 82];
+        let status =
+            unsafe { uuid_readable_generate_from(UUID.as_ptr(), out.as_mut_ptr(), out.len()) };
+        assert_eq!(status, UuidReadableStatus::BufferTooSmall);
+        assert_eq!(out[0], 0);
+
+        let status =
+            unsafe { uuid_readable_generate_from(ptr::null(), out.as_mut_ptr(), out.len()) };
+        assert_eq!(status, UuidReadableStatus::NullPointer);
+
+        let mut uuid = [0; 16];
+        let status = unsafe {
+            uuid_readable_generate_inverse(c_string("Purdy Fusco").as_ptr(), uuid.as_mut_ptr())
+        };
+        assert_eq!(status, UuidReadableStatus::WordCount);
+        let status = unsafe {
+            uuid_readable_generate_inverse(
+                c_string(&SENTENCE.replace("Kask", "Kasky")).as_ptr(),
+                uuid.as_mut_ptr(),
+            )
+        };
+        assert_eq!(status, UuidReadableStatus::UnknownWord);
+        let status =
+            unsafe { uuid_readable_generate_inverse(b"\xff\0".as_ptr().cast(), uuid.as_mut_ptr()) };
+        assert_eq!(status, UuidReadableStatus::InvalidUtf8);
+
+        let message = |status| unsafe { CStr::from_ptr(uuid_readable_status_message(status)) };
+        assert_eq!(
+            message(UuidReadableStatus::UnknownWord as u32).to_str(),
+            Ok("unknown word")
+        );
+        assert_eq!(
+            message(UuidReadableStatus::Ambiguous as u32).to_str(),
+            Ok("the sentence matches several UUIDs")
+        );
+        assert_eq!(message(13).to_str(), Ok("unknown status"));
+        assert_eq!(message(u32::MAX).to_str(), Ok("unknown status"));
+    }
+}
@@ -0,0 +1,370 @@
+//! Rewrite the UUIDs found in arbitrary text, such as logs, into sentences
+//! and the other way around.
+//!
+//! ```rust
+//! use uuid_readable_rs::filter::{encode_text, decode_text, Mode};
+//!
+//! let line = "GET /users/0ee001c7-12f3-4b29-a4cc-f48838b3587a 200";
+//! let encoded = encode_text(line, Mode::Replace);
+//! assert_eq!(
+//!     encoded,
+//!     "GET /users/Purdy Fusco Kask the loki of Manteo observed Barbe Lehet Pardew and 26 hard herons 200"
+//! );
+//! assert_eq!(decode_text(&encoded, Mode::Replace), line);
+//! ```
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::io::{self, BufRead, Write};
+
+use uuid::Uuid;
+
+use crate::{generate_from, generate_inverse_strict, is_punctuation};
+
+/// What to do with each UUID or sentence found in the text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Replace it with its conversion
+    Replace,
+    /// Keep it and append its conversion between parentheses
+    Append,
+}
+
+/// Rewrite every UUID found in `text` into its long sentence.
+///
+/// The hyphenated, simple (32 hex digits), braced and URN forms are
+/// recognized, as long as they are not part of a longer word.
+pub fn encode_text(text: &str, mode: Mode) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut last = 0;
+
+    while let Some((start, end, uuid)) = find_uuid(text, last) {
+        out.push_str(&text[last..start]);
+        match mode {
+            Mode::Replace => out.push_str(&generate_from(uuid)),
+            Mode::Append => {
+                out.push_str(&text[start..end]);
+                out.push_str(" (");
+                out.push_str(&generate_from(uuid));
+                out.push(')');
+            }
+        }
+        last = end;
+    }
+    out.push_str(&text[last..]);
+
+    out
+}
+
+/// Rewrite every long sentence found in `text` back into its hyphenated UUID.
+///
+/// Only the sentences written exactly as generated are recognized, see
+/// `generate_inverse_strict`. The checksum of `generate_checked_from` and the
+/// parity of `generate_recoverable_from` are not part of the sentence found,
+/// they are left in the text: `... hard herons in Hughson` becomes
+/// `<uuid> in Hughson`.
+pub fn decode_text(text: &str, mode: Mode) -> String {
+    let spans = word_spans(text);
+    let mut out = String::with_capacity(text.len());
+    let mut last = 0;
+
+    let mut i = 0;
+    while i + 15 <= spans.len() {
+        let (start, end) = (spans[i].0, spans[i + 14].1);
+        match generate_inverse_strict(&text[start..end]) {
+            Ok(uuid) => {
+                out.push_str(&text[last..start]);
+                match mode {
+                    Mode::Replace => out.push_str(&uuid.to_string()),
+                    Mode::Append => {
+                        out.push_str(&text[start..end]);
+                        out.push_str(" (");
+                        out.push_str(&uuid.to_string());
+                        out.push(')');
+                    }
+                }
+                last = end;
+                i += 15;
+            }
+            Err(_) => i += 1,
+        }
+    }
+    out.push_str(&text[last..]);
+
+    out
+}
+
+/// Apply `encode_text` to each line of `reader`, writing the result to `writer`.
+///
+/// Lines which are not valid UTF-8 are copied untouched.
+#[cfg(feature = "std")]
+pub fn encode_stream<R: BufRead, W: Write>(reader: R, writer: W, mode: Mode) -> io::Result<()> {
+    rewrite_stream(reader, writer, |line| encode_text(line, mode))
+}
+
+/// Apply `decode_text` to each line of `reader`, writing the result to `writer`.
+///
+/// Lines which are not valid UTF-8 are copied untouched.
+#[cfg(feature = "std")]
+pub fn decode_stream<R: BufRead, W: Write>(reader: R, writer: W, mode: Mode) -> io::Result<()> {
+    rewrite_stream(reader, writer, |line| decode_text(line, mode))
+}
+
+#[cfg(feature = "std")]
+fn rewrite_stream<R, W, F>(mut reader: R, mut writer: W, f: F) -> io::Result<()>
+where
+    R: BufRead,
+    W: Write,
+    F: Fn(&str) -> String,
+{
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+        if reader.read_until(b'\n', &mut buf)? == 0 {
+            break;
+        }
+        match std::str::from_utf8(&buf) {
+            Ok(line) => writer.write_all(f(line).as_bytes())?,
+            Err(_) => writer.write_all(&buf)?,
+        }
+    }
+
+    writer.flush()
+}
+
+/// Find the first UUID of `text` from the byte `from`, returning its span
+fn find_uuid(text: &str, from: usize) -> Option<(usize, usize, Uuid)> {
+    let bytes = text.as_bytes();
+
+    for start in from..bytes.len() {
+        // A UUID can't start in the middle of a word
+        if start > 0 && bytes[start - 1].is_ascii_alphanumeric() {
+            continue;
+        }
+        if let Some((len, uuid)) = match_uuid(&bytes[start..]) {
+            let end = start + len;
+            // Nor end in the middle of one
+            if end == bytes.len() || !bytes[end].is_ascii_alphanumeric() {
+                return Some((start, end, uuid));
+            }
+        }
+    }
+
+    None
+}
+
+/// Match a UUID in any of its textual forms at the start of `bytes`
+fn match_uuid(bytes: &[u8]) -> Option<(usize, Uuid)> {
+    const URN: &[u8] = b"urn:uuid:";
+
+    if bytes.len() >= URN.len() && bytes[..URN.len()].eq_ignore_ascii_case(URN) {
+        return match_hyphenated(&bytes[URN.len()..]).map(|uuid| (URN.len() + 36, uuid));
+    }
+    if bytes.first() == Some(&b'{') {
+        return match_hyphenated(&bytes[1..])
+            .filter(|_| bytes.get(37) == Some(&b'}'))
+            .map(|uuid| (38, uuid));
+    }
+
+    match_hyphenated(bytes)
+        .map(|uuid| (36, uuid))
+        .or_else(|| match_simple(bytes).map(|uuid| (32, uuid)))
+}
+
+/// Match `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx` at the start of `bytes`
+fn match_hyphenated(bytes: &[u8]) -> Option<Uuid> {
+    let candidate = bytes.get(..36)?;
+    let valid = candidate.iter().enumerate().all(|(i, b)| match i {
+        8 | 13 | 18 | 23 => *b == b'-',
+        _ => b.is_ascii_hexdigit(),
+    });
+
+    if valid {
+        parse(candidate)
+    } else {
+        None
+    }
+}
+
+/// Match 32 hexadecimal digits at the start of `bytes`
+fn match_simple(bytes: &[u8]) -> Option<Uuid> {
+    let candidate = bytes.get(..32)?;
+
+    if candidate.iter().all(u8::is_ascii_hexdigit) {
+        parse(candidate)
+    } else {
+        None
+    }
+}
+
+#[inline]
+fn parse(candidate: &[u8]) -> Option<Uuid> {
+    core::str::from_utf8(candidate)
+        .ok()
+        .and_then(|s| Uuid::parse_str(s).ok())
+}
+
+/// Characters a word of the lists can be made of, including the hyphen of
+/// names such as `Anna-Maria`
+#[inline]
+fn is_word_char(c: char) -> bool {
+    !is_punctuation(c) || c == '-'
+}
+
+/// For each whitespace separated token of `text`, the byte offset at which a
+/// sentence starting in it would start, and the one at which a sentence
+/// ending in it would end. This allows to find the sentences glued to some
+/// punctuation, as in `id="Purdy ... herons",`.
+fn word_spans(text: &str) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut offset = 0;
+
+    for token in text.split_whitespace() {
+        // split_whitespace yields subslices of text in order
+        let start = offset + text[offset..].find(token).unwrap_or(0);
+        offset = start + token.len();
+
+        let head = token.rfind(|c| !is_word_char(c)).map_or(0, |i| {
+            i + token[i..].chars().next().map_or(0, char::len_utf8)
+        });
+        let tail = token.find(|c| !is_word_char(c)).unwrap_or(token.len());
+        if head < token.len() || tail > 0 {
+            spans.push((start + head, start + tail));
+        }
+    }
+
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    const SENTENCE: &str =
+        "Purdy Fusco Kask the loki of Manteo observed Barbe Lehet Pardew and 26 hard herons";
+    const ZEROED: &str =
+        "Fusco Fusco Fusco the muleteer of Katy suspended Fusco Fusco Fusco and 0 mysterious rooks";
+
+    #[test]
+    fn test_encode_forms() {
+        let forms = [
+            "0ee001c7-12f3-4b29-a4cc-f48838b3587a",
+            "0EE001C7-12F3-4B29-A4CC-F48838B3587A",
+            "0ee001c712f34b29a4ccf48838b3587a",
+            "{0ee001c7-12f3-4b29-a4cc-f48838b3587a}",
+            "urn:uuid:0ee001c7-12f3-4b29-a4cc-f48838b3587a",
+        ];
+
+        for form in forms.iter() {
+            let line = format!("id={} done", form);
+            assert_eq!(
+                encode_text(&line, Mode::Replace),
+                format!("id={} done", SENTENCE)
+            );
+        }
+    }
+
+    #[test]
+    fn test_encode_log_lines() {
+        let line = "2021-03-04T10:00:00Z INFO user=0ee001c7-12f3-4b29-a4cc-f48838b3587a \
+                    parent={00000000-0000-0000-0000-000000000000} status=ok\n";
+        assert_eq!(
+            encode_text(line, Mode::Replace),
+            format!(
+                "2021-03-04T10:00:00Z INFO user={} parent={} status=ok\n",
+                SENTENCE, ZEROED
+            )
+        );
+        assert_eq!(
+            encode_text(line, Mode::Append),
+            format!(
+                "2021-03-04T10:00:00Z INFO user=0ee001c7-12f3-4b29-a4cc-f48838b3587a ({}) \
+                 parent={{00000000-0000-0000-0000-000000000000}} ({}) status=ok\n",
+                SENTENCE, ZEROED
+            )
+        );
+    }
+
+    #[test]
+    fn test_encode_ignores_longer_words() {
+        let lines = [
+            "sha=0ee001c712f34b29a4ccf48838b3587a0ee001c7",
+            "x0ee001c7-12f3-4b29-a4cc-f48838b3587a",
+            "0ee001c7-12f3-4b29-a4cc-f48838b3587ab",
+            "0ee001c7-12f3-4b29-a4cc-f48838b3587",
+            "no uuid here",
+        ];
+
+        for line in lines.iter() {
+            assert_eq!(encode_text(line, Mode::Replace), *line);
+        }
+    }
+
+    #[test]
+    fn test_decode_log_lines() {
+        let line = format!("INFO user=\"{}\", parent {}.", SENTENCE, ZEROED);
+        assert_eq!(
+            decode_text(&line, Mode::Replace),
+            "INFO user=\"0ee001c7-12f3-4b29-a4cc-f48838b3587a\", \
+             parent 00000000-0000-0000-0000-000000000000."
+        );
+        assert_eq!(
+            decode_text(&line, Mode::Append),
+            format!(
+                "INFO user=\"{} (0ee001c7-12f3-4b29-a4cc-f48838b3587a)\", \
+                 parent {} (00000000-0000-0000-0000-000000000000).",
+                SENTENCE, ZEROED
+            )
+        );
+
+        // The words following the sentence are kept
+        let uuid = "0ee001c7-12f3-4b29-a4cc-f48838b3587a";
+        let checked = crate::generate_checked_from(Uuid::parse_str(uuid).unwrap());
+        assert_eq!(
+            decode_text(&checked, Mode::Replace),
+            format!("{} in Hughson", uuid)
+        );
+        let recoverable = crate::generate_recoverable_from(Uuid::parse_str(uuid).unwrap());
+        assert_eq!(
+            decode_text(&recoverable, Mode::Replace),
+            format!("{} with Prior Chiquita", uuid)
+        );
+
+        // Not quite a sentence
+        let line = "Purdy Fusco Kask the loki of Manteo observed Barbe Lehet Pardew and 26 hard";
+        assert_eq!(decode_text(line, Mode::Replace), line);
+    }
+
+    #[test]
+    fn test_decode_replacement_char() {
+        // `Am\u{fffd}lie` only fits in the third name, which uses 14 bits
+        // starting at the 24th one, hence never at the edge of a sentence
+        let uuid = (0..1u128 << 14)
+            .map(|i| Uuid::from_u128(i << 91))
+            .find(|uuid| generate_from(*uuid).contains(" Am\u{fffd}lie "))
+            .unwrap();
+        let line = format!("user={} logged in", generate_from(uuid));
+        assert_eq!(
+            decode_text(&line, Mode::Replace),
+            format!("user={} logged in", uuid)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_streams() {
+        let input: &[u8] = b"a 0ee001c7-12f3-4b29-a4cc-f48838b3587a\n\nb\xff\nc";
+        let mut encoded = Vec::new();
+        encode_stream(input, &mut encoded, Mode::Replace).unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&encoded),
+            format!("a {}\n\nb\u{fffd}\nc", SENTENCE)
+        );
+
+        let mut decoded = Vec::new();
+        decode_stream(&encoded[..], &mut decoded, Mode::Replace).unwrap();
+        assert_eq!(decoded, input);
+    }
+}
@@ -0,0 +1,352 @@
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use uuid::Uuid;
+
+use crate::{
+    checksum, split_words, Category, Dictionary, ParseError, Part, Wordlist, CHECKSUM_CONNECTOR,
+};
+
+/// Result of `generate_inverse_fuzzy`, the UUID found along with what had
+/// to be corrected in the sentence to get it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FuzzyMatch {
+    /// The UUID of the corrected sentence
+    pub uuid: Uuid,
+    /// The corrected sentence, as `generate_from` would create it
+    pub sentence: String,
+    /// Every word that was replaced, empty if the sentence was exact
+    pub corrections: Vec<Correction>,
+}
+
+/// A word of the sentence replaced by the closest one of its list.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Correction {
+    /// Position of the word in the sentence, starting at 0
+    pub position: usize,
+    /// Category of the list the word was searched in
+    pub category: Category,
+    /// The word as it was written
+    pub original: String,
+    /// The word it was replaced with
+    pub corrected: String,
+    /// Edit distance between the original and the corrected word
+    pub distance: usize,
+    /// Other words of the list at the same distance, which could have been
+    /// meant as well
+    pub alternatives: Vec<String>,
+}
+
+impl FuzzyMatch {
+    /// True if no word had to be corrected
+    pub fn is_exact(&self) -> bool {
+        self.corrections.is_empty()
+    }
+
+    /// True if at least one correction had several equally close candidates
+    pub fn is_ambiguous(&self) -> bool {
+        self.corrections.iter().any(|c| !c.alternatives.is_empty())
+    }
+
+    /// Rough estimate, between 0 and 1, that the UUID is the intended one.
+    ///
+    /// Each correction weights by the share of characters left untouched,
+    /// divided by the number of equally close candidates.
+    pub fn confidence(&self) -> f64 {
+        self.corrections
+            .iter()
+            .map(|c| {
+                let len = c.original.chars().count().max(c.corrected.chars().count());
+                let similarity = 1.0 - c.distance as f64 / len as f64;
+                similarity / (c.alternatives.len() + 1) as f64
+            })
+            .product()
+    }
+}
+
+/// Get the original uuid from a sentence which may contain typos.
+///
+/// The sentence is first matched loosely, as with `generate_inverse`. Then,
+/// each word not found in its list is replaced by the closest word (using the
+/// edit distance, ignoring case) among the ones its slot can hold. A word
+/// for which more than half of the characters would have to change is
+/// considered unknown.
+///
+/// The checksum added by `generate_checked_from`, if any, is corrected as
+/// well and must match the corrected UUID, in which case `sentence` holds it.
+/// Any other words after the sentence are ignored.
+///
+/// ```rust
+/// use uuid_readable_rs::generate_inverse_fuzzy;
+///
+/// let m = generate_inverse_fuzzy(
+///     "Purdy Fusko Kask the loki of Manteo observed Barbe Lehet Pardew and 26 hard herons",
+/// )
+/// .unwrap();
+/// assert_eq!(m.uuid.to_string(), "0ee001c7-12f3-4b29-a4cc-f48838b3587a");
+/// assert_eq!(m.corrections[0].corrected, "Fusco");
+/// ```
+pub fn generate_inverse_fuzzy<S: AsRef<str>>(sentence: S) -> Result<FuzzyMatch, ParseError> {
+    _inverse_fuzzy(Dictionary::builtin(), sentence.as_ref())
+}
+
+pub(crate) fn _inverse_fuzzy(dict: &Dictionary, sentence: &str) -> Result<FuzzyMatch, ParseError> {
+    let template = dict.long_template();
+    let expected = template.parts().len();
+    let splitted: Vec<&str> = split_words(sentence);
+    // The checksum added by `generate_checked_from` may be misspelled too
+    let checksum_word = match splitted.get(expected..) {
+        Some([connector, word]) if connector.eq_ignore_ascii_case(CHECKSUM_CONNECTOR) => {
+            Some(*word)
+        }
+        _ => None,
+    };
+    // Sanity check that we have enough words to work with
+    if splitted.len() < expected {
+        return Err(ParseError::WordCount {
+            expected,
+            found: splitted.len(),
+        });
+    }
+
+    let mut values = Vec::with_capacity(expected);
+    let mut corrections = Vec::new();
+    for (pos, part) in template.parts().iter().enumerate() {
+        let word = splitted[pos];
+        let max = 1u16 << part.bits();
+        values.push(match part {
+            Part::Literal(_) => continue,
+            Part::Word { category, .. } => {
+                let list = dict.list(*category);
+                match list.position_any_case(word) {
+                    Some(idx) if idx < max as usize => idx as u16,
+                    _ => {
+                        let (idx, correction) = closest(list, max as usize, word, pos, *category)?;
+                        corrections.push(correction);
+                        idx as u16
+                    }
+                }
+            }
+            Part::Number { .. } => match word.parse::<u16>() {
+                Ok(number) if number < max => number,
+                Ok(_) => {
+                    return Err(ParseError::NumberOutOfRange {
+                        position: pos,
+                        max: max - 1,
+                    })
+                }
+                Err(_) => return Err(ParseError::InvalidNumber { position: pos }),
+            },
+        });
+    }
+
+    let uuid =
+        Uuid::from_slice(&template.assemble(&values)).expect("the long sentence holds 128 bits");
+
+    let sentence = match checksum_word {
+        Some(word) => {
+            let position = expected + 1;
+            let list = dict.list(Category::Place);
+            let idx = match list.position_any_case(word) {
+                Some(idx) => idx,
+                None => {
+                    let (idx, correction) =
+                        closest(list, list.len(), word, position, Category::Place)?;
+                    corrections.push(correction);
+                    idx
+                }
+            };
+            if idx != checksum(&uuid) {
+                return Err(ParseError::ChecksumMismatch);
+            }
+            crate::_generate_checked(dict, &uuid)
+        }
+        None => crate::_generate(dict, &uuid),
+    };
+
+    Ok(FuzzyMatch {
+        uuid,
+        sentence,
+        corrections,
+    })
+}
+
+/// Find the closest word to `word` among the first `len` ones of `list`
+fn closest(
+    list: &dyn Wordlist,
+    len: usize,
+    word: &str,
+    position: usize,
+    category: Category,
+) -> Result<(usize, Correction), ParseError> {
+    let lowered: Vec<char> = word.to_lowercase().chars().collect();
+
+    let mut best = usize::MAX;
+    let mut candidates: Vec<usize> = Vec::new();
+    for idx in 0..len {
+        let candidate: Vec<char> = list.word(idx).to_lowercase().chars().collect();
+        let distance = edit_distance(&lowered, &candidate);
+        if distance < best {
+            best = distance;
+            candidates.clear();
+        }
+        if distance == best {
+            candidates.push(idx);
+        }
+    }
+
+    // Don't turn a completely different word into a valid one
+    if candidates.is_empty() || best > (lowered.len() / 2).max(1) {
+        return Err(ParseError::UnknownWord { position, category });
+    }
+
+    let idx = candidates[0];
+    Ok((
+        idx,
+        Correction {
+            position,
+            category,
+            original: word.to_string(),
+            corrected: list.word(idx).to_string(),
+            distance: best,
+            alternatives: candidates[1..]
+                .iter()
+                .map(|&i| list.word(i).to_string())
+                .collect(),
+        },
+    ))
+}
+
+/// Number of insertions, deletions, substitutions and transpositions of two
+/// adjacent characters to go from `a` to `b` (optimal string alignment).
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut d = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            d[i][j] = (d[i - 1][j - 1] + cost)
+                .min(d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+
+    d[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Words;
+    use alloc::format;
+
+    const SENTENCE: &str =
+        "Purdy Fusco Kask the loki of Manteo observed Barbe Lehet Pardew and 26 hard herons";
+
+    #[test]
+    fn test_edit_distance() {
+        let chars = |s: &str| s.chars().collect::<Vec<_>>();
+        assert_eq!(edit_distance(&chars("fusco"), &chars("fusko")), 1);
+        assert_eq!(edit_distance(&chars("manteo"), &chars("mantoe")), 1);
+        assert_eq!(edit_distance(&chars("kitten"), &chars("sitting")), 3);
+        assert_eq!(edit_distance(&chars(""), &chars("abc")), 3);
+    }
+
+    #[test]
+    fn test_fuzzy_exact() {
+        let m = generate_inverse_fuzzy(SENTENCE).unwrap();
+        assert!(m.is_exact());
+        assert_eq!(m.confidence(), 1.0);
+        assert_eq!(m.sentence, SENTENCE);
+    }
+
+    #[test]
+    fn test_fuzzy_typos() {
+        let m = generate_inverse_fuzzy(
+            "purdy Fusko Kask the loki of Mnteo observed Barbe Lehet Pardew and 26 hard herrons",
+        )
+        .unwrap();
+        let uuid = Uuid::parse_str("0ee001c7-12f3-4b29-a4cc-f48838b3587a").unwrap();
+        assert_eq!(m.uuid, uuid);
+        assert_eq!(m.sentence, SENTENCE);
+
+        let corrected: Vec<_> = m.corrections.iter().map(|c| c.corrected.as_str()).collect();
+        assert_eq!(corrected, ["Fusco", "Manteo", "herons"]);
+        assert_eq!(m.corrections[0].position, 1);
+        assert_eq!(m.corrections[0].original, "Fusko");
+        assert_eq!(m.corrections[0].distance, 1);
+        assert!(m.confidence() < 1.0);
+    }
+
+    #[test]
+    fn test_fuzzy_unknown() {
+        let rev = generate_inverse_fuzzy(
+            "Purdy Fusco Kask the loki of Manteo observed Barbe Lehet Pardew and 26 hard xyzxyzxyz",
+        );
+        assert_eq!(
+            rev,
+            Err(ParseError::UnknownWord {
+                position: 14,
+                category: Category::Animal
+            })
+        );
+    }
+
+    #[test]
+    fn test_fuzzy_checksum() {
+        let checked = format!("{} in Hughson", SENTENCE);
+        let m = generate_inverse_fuzzy(checked.replace("Manteo", "Mnteo")).unwrap();
+        assert_eq!(m.uuid.to_string(), "0ee001c7-12f3-4b29-a4cc-f48838b3587a");
+        assert_eq!(m.sentence, checked);
+        assert_eq!(m.corrections.len(), 1);
+
+        let m = generate_inverse_fuzzy(checked.replace("Hughson", "Hugson")).unwrap();
+        assert_eq!(m.sentence, checked);
+        assert_eq!(m.corrections[0].position, 16);
+        assert_eq!(m.corrections[0].corrected, "Hughson");
+
+        // A valid but wrong word
+        assert_eq!(
+            generate_inverse_fuzzy(checked.replace("Purdy", "Fusco")),
+            Err(ParseError::ChecksumMismatch)
+        );
+
+        // Trailing words are ignored, as with `generate_inverse`
+        let m = generate_inverse_fuzzy(format!("{} in Hughson today", SENTENCE)).unwrap();
+        assert_eq!(m.uuid.to_string(), "0ee001c7-12f3-4b29-a4cc-f48838b3587a");
+        assert_eq!(m.sentence, SENTENCE);
+        let m = generate_inverse_fuzzy(format!("{} today", SENTENCE)).unwrap();
+        assert_eq!(m.sentence, SENTENCE);
+        assert!(m.corrections.is_empty());
+    }
+
+    #[test]
+    fn test_fuzzy_dictionary() {
+        let animals: Vec<String> = (0..128).map(|i| format!("robot{}", i)).collect();
+        let dictionary = Dictionary::builder()
+            .list(Category::Animal, Words::new(animals))
+            .build()
+            .unwrap();
+        let sentence = dictionary.generate_from(Uuid::nil());
+        assert!(sentence.ends_with(" robot0"), "{}", sentence);
+
+        let m = dictionary
+            .generate_inverse_fuzzy(sentence.replace("robot0", "robt0"))
+            .unwrap();
+        assert_eq!(m.uuid, Uuid::nil());
+        assert_eq!(m.sentence, sentence);
+        assert_eq!(m.corrections[0].corrected, "robot0");
+        // The builtin animals are not searched
+        assert!(!generate_inverse_fuzzy(&sentence).unwrap().is_exact());
+    }
+}
@@ -1,10 +1,11 @@
 //! Generate easy to remember sentences that acts as human readable UUIDs.
 //!
-//! - Built on UUID v4
+//! - Built on UUID v4, with time-ordered (v7) and name-based (v5) ones behind the `v7` and `v5` features
 //! - Optionally pass your UUID to derive a sentence from it
 //! - Grammatically _correct_ sentences
 //! - Easy to remember (or at least part of it)
 //! - Size choice (32-bit token or 128-bit token using `short()` or `generate()` respectively)
+//! - A 64-bit sentence for your own ids using `medium_from()`
 //!
 //! ## Security
 //! This project does not mean to be crypto safe! **Don't use this as a secure random generator**.
@@ -50,6 +51,7 @@
 //!
 //! ## Example
 //! ```rust
+//! # #[cfg(feature = "random")] {
 //! use uuid::Uuid;
 //! use uuid_readable_rs::{generate_from, short_from, generate, short, generate_inverse};
 //!
@@ -65,21 +67,63 @@
 //! // Or let uuid_readable_rs handle the Uuid generation
 //! let sentence_128: String = generate();
 //! let sentence_32: String = short();
+//! # }
 //! ```
+//!
+//! ## Features
+//! The crate is `no_std`, only needing `alloc`, when its default features are
+//! disabled:
+//! - `std` (default): `encode_stream()` and `decode_stream()` of the `filter`
+//!   module, working on `std::io`
+//! - `random` (default): `generate()`, `short()` and the other functions
+//!   creating a random UUID, using `getrandom`
+//!
+//! `write_long()` and `write_short()` don't allocate, to write sentences on
+//! targets without a heap.
+
+#![no_std]
 
-#[macro_use]
-extern crate anyhow;
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
 
-use anyhow::{Context, Result};
-use data::{
-    adjectives::ADJECTIVES, animals::ANIMALS, names::NAMES, personal_nouns::PERSONAL_NOUNS,
-    places::PLACES, verbs::VERBS,
-};
 use uuid::Uuid;
 
+pub use batch::{generate_inverse_many, generate_many, ReadableIterExt, Sentences, Uuids};
+pub use dictionary::{Dictionary, DictionaryBuilder, Wordlist, Words};
+pub use error::{Category, DictionaryError, EncodeError, ParseError, TemplateError};
+pub use fuzzy::{generate_inverse_fuzzy, Correction, FuzzyMatch};
+pub use partial::{generate_inverse_partial, PartialMatch};
+pub use readable::ReadableUuid;
+#[cfg(feature = "random")]
+pub use recover::generate_recoverable;
+pub use recover::{generate_recoverable_from, recover_inverse};
+pub use template::{Part, Template};
+
+mod batch;
 mod data;
-
-// TODO - Add a reverse method for sentence -> uuid
+mod dictionary;
+mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod filter;
+mod fuzzy;
+mod partial;
+#[cfg(feature = "python")]
+mod python;
+mod readable;
+mod recover;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod template;
+#[cfg(feature = "wasm")]
+mod wasm;
 
 /// Mask used for the long version, this allow us to convert a 16 items
 /// totalling 128 bit into 12 items for the same number of bits.
@@ -97,6 +141,58 @@ mod data;
 /// - 7  => 2**7  = 128     ==> ANIMALS
 const NORMAL: [u8; 12] = [12, 11, 14, 13, 13, 10, 12, 11, 14, 5, 6, 7];
 
+/// Layout of the long sentence, each slot using the bits of `NORMAL`.
+const LONG_LAYOUT: &[Part] = &[
+    Part::Word {
+        category: Category::Name,
+        bits: NORMAL[0],
+    },
+    Part::Word {
+        category: Category::Name,
+        bits: NORMAL[1],
+    },
+    Part::Word {
+        category: Category::Name,
+        bits: NORMAL[2],
+    },
+    Part::Literal(Cow::Borrowed("the")),
+    Part::Word {
+        category: Category::PersonalNoun,
+        bits: NORMAL[3],
+    },
+    Part::Literal(Cow::Borrowed("of")),
+    Part::Word {
+        category: Category::Place,
+        bits: NORMAL[4],
+    },
+    Part::Word {
+        category: Category::Verb,
+        bits: NORMAL[5],
+    },
+    Part::Word {
+        category: Category::Name,
+        bits: NORMAL[6],
+    },
+    Part::Word {
+        category: Category::Name,
+        bits: NORMAL[7],
+    },
+    Part::Word {
+        category: Category::Name,
+        bits: NORMAL[8],
+    },
+    Part::Literal(Cow::Borrowed("and")),
+    Part::Number { bits: NORMAL[9] },
+    Part::Word {
+        category: Category::Adjective,
+        bits: NORMAL[10],
+    },
+    Part::Word {
+        category: Category::Animal,
+        bits: NORMAL[11],
+    },
+];
+
 /// Used for low entropy in the short methods. Higher chances of collisions
 /// between two generated sentences. 32 bit into 5 items.
 /// - 6 => 2**6 = 64        ==> NAMES
@@ -106,6 +202,130 @@ const NORMAL: [u8; 12] = [12, 11, 14, 13, 13, 10, 12, 11, 14, 5, 6, 7];
 /// - 5 => 2**5 = 32        ==> ANIMALS
 const SHORT: [u8; 5] = [6, 6, 7, 8, 5];
 
+/// Layout of the short sentence, each slot using the bits of `SHORT`.
+const SHORT_LAYOUT: &[Part] = &[
+    Part::Word {
+        category: Category::Name,
+        bits: SHORT[0],
+    },
+    Part::Word {
+        category: Category::Verb,
+        bits: SHORT[1],
+    },
+    Part::Literal(Cow::Borrowed("by")),
+    Part::Number { bits: SHORT[2] },
+    Part::Word {
+        category: Category::Adjective,
+        bits: SHORT[3],
+    },
+    Part::Word {
+        category: Category::Animal,
+        bits: SHORT[4],
+    },
+];
+
+/// Mask used for the medium version, 64 bit into 6 items.
+/// - 14 => 2**14 = 16384   ==> NAMES
+/// - 13 => 2**13 = 8192    ==> PERSONAL_NOUNS
+/// - 13 => 2**13 = 8192    ==> PLACES
+/// - 10 => 2**10 = 1024    ==> VERBS
+/// - 7  => 2**7  = 128     ==> ADJECTIVES
+/// - 7  => 2**7  = 128     ==> ANIMALS
+const MEDIUM: [u8; 6] = [14, 13, 13, 10, 7, 7];
+
+/// Layout of the medium sentence, each slot using the bits of `MEDIUM`.
+const MEDIUM_LAYOUT: &[Part] = &[
+    Part::Word {
+        category: Category::Name,
+        bits: MEDIUM[0],
+    },
+    Part::Literal(Cow::Borrowed("the")),
+    Part::Word {
+        category: Category::PersonalNoun,
+        bits: MEDIUM[1],
+    },
+    Part::Literal(Cow::Borrowed("of")),
+    Part::Word {
+        category: Category::Place,
+        bits: MEDIUM[2],
+    },
+    Part::Word {
+        category: Category::Verb,
+        bits: MEDIUM[3],
+    },
+    Part::Word {
+        category: Category::Adjective,
+        bits: MEDIUM[4],
+    },
+    Part::Word {
+        category: Category::Animal,
+        bits: MEDIUM[5],
+    },
+];
+
+/// Mask used for the compact version, 122 bit (the random bits of a v4 UUID)
+/// into 11 items.
+/// - 14 => 2**14 = 16384   ==> NAMES
+/// - 14 => 2**14 = 16384   ==> NAMES
+/// - 14 => 2**14 = 16384   ==> NAMES
+/// - 13 => 2**13 = 8192    ==> PERSONAL_NOUNS
+/// - 13 => 2**13 = 8192    ==> PLACES
+/// - 10 => 2**10 = 1024    ==> VERBS
+/// - 14 => 2**14 = 16384   ==> NAMES
+/// - 14 => 2**14 = 16384   ==> NAMES
+/// - 2  => 2**2  = 4       ==> MAX 4 as u8
+/// - 7  => 2**7  = 128     ==> ADJECTIVES
+/// - 7  => 2**7  = 128     ==> ANIMALS
+const COMPACT: [u8; 11] = [14, 14, 14, 13, 13, 10, 14, 14, 2, 7, 7];
+
+/// Layout of the compact sentence, each slot using the bits of `COMPACT`.
+const COMPACT_LAYOUT: &[Part] = &[
+    Part::Word {
+        category: Category::Name,
+        bits: COMPACT[0],
+    },
+    Part::Word {
+        category: Category::Name,
+        bits: COMPACT[1],
+    },
+    Part::Word {
+        category: Category::Name,
+        bits: COMPACT[2],
+    },
+    Part::Literal(Cow::Borrowed("the")),
+    Part::Word {
+        category: Category::PersonalNoun,
+        bits: COMPACT[3],
+    },
+    Part::Literal(Cow::Borrowed("of")),
+    Part::Word {
+        category: Category::Place,
+        bits: COMPACT[4],
+    },
+    Part::Word {
+        category: Category::Verb,
+        bits: COMPACT[5],
+    },
+    Part::Word {
+        category: Category::Name,
+        bits: COMPACT[6],
+    },
+    Part::Word {
+        category: Category::Name,
+        bits: COMPACT[7],
+    },
+    Part::Literal(Cow::Borrowed("and")),
+    Part::Number { bits: COMPACT[8] },
+    Part::Word {
+        category: Category::Adjective,
+        bits: COMPACT[9],
+    },
+    Part::Word {
+        category: Category::Animal,
+        bits: COMPACT[10],
+    },
+];
+
 /// Convert an array of bytes to a Vec of individuals bits (1-0)
 fn to_bits(bytes: &[u8]) -> Vec<u8> {
     let mut bits: Vec<u8> = Vec::with_capacity(128);
@@ -117,12 +337,13 @@ fn to_bits(bytes: &[u8]) -> Vec<u8> {
     bits
 }
 
-/// Convert an array of bytes to a Vec of individuals bits (1-0)
-fn to_bits_parted(bytes: &[u16]) -> Vec<u8> {
+/// Convert an array of parts to a Vec of individuals bits (1-0), each
+/// part `i` being `parts[i]` bits long
+fn to_bits_parted(parts: &[u8], bytes: &[u16]) -> Vec<u8> {
     let mut bits: Vec<u8> = Vec::with_capacity(128);
 
     for (i, b) in bytes.iter().enumerate() {
-        bits.extend(u16_to_bits(*b, NORMAL[i]));
+        bits.extend(u16_to_bits(*b, parts[i]));
     }
 
     bits
@@ -152,178 +373,603 @@ fn to_byte(bits: &[u8]) -> u16 {
     _byte
 }
 
-/// Convert bytes to bits and group them into 12 distinct numbers
-fn partition(parts: &[u8], bytes: &[u8]) -> [usize; 12] {
+/// Punctuation that can surround a word, the underscore being part of some
+/// words (`Jaime_`) it is kept.
+#[inline]
+fn is_punctuation(c: char) -> bool {
+    !c.is_alphanumeric() && c != '_'
+}
+
+/// Split a sentence typed by a human into its words. Any run of whitespace
+/// separates two words, and the punctuation around the words is dropped.
+fn split_words(sentence: &str) -> Vec<&str> {
+    sentence
+        .split_whitespace()
+        .map(|w| w.trim_matches(is_punctuation))
+        .filter(|w| !w.is_empty())
+        .collect()
+}
+
+/// Same as `split_words`, but keeping the words replaced by a blank (`?`
+/// or `*`), given as `None`.
+fn split_blanks(sentence: &str) -> Vec<Option<&str>> {
+    sentence
+        .split_whitespace()
+        .filter_map(|token| {
+            let word = token.trim_matches(is_punctuation);
+            if !word.is_empty() {
+                Some(Some(word))
+            } else if token.contains(['?', '*']) {
+                Some(None)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Parse the number found at `position` of a sentence
+#[inline]
+fn parse_number(word: &str, position: usize) -> Result<u16, ParseError> {
+    word.parse::<u16>()
+        .map_err(|_| ParseError::InvalidNumber { position })
+}
+
+/// Convert bytes to bits and group them into one number per part
+fn partition(parts: &[u8], bytes: &[u8]) -> Vec<usize> {
     let mut bits: Vec<u8> = to_bits(bytes);
 
-    let mut _bytes: [usize; 12] = [0; 12];
-    for (idx, p) in parts.iter().enumerate() {
+    let mut _bytes: Vec<usize> = Vec::with_capacity(parts.len());
+    for p in parts.iter() {
         let tmp = bits.drain(0..(*p as usize));
-        _bytes[idx] = to_byte(tmp.as_slice()) as usize;
+        _bytes.push(to_byte(tmp.as_slice()) as usize);
     }
 
     _bytes
 }
 
-/// Convert bits to bytes, grouping them 8 by 8 because it's u8
-fn de_partition(bits: &[u8]) -> [u8; 16] {
-    let mut bytes = [0; 16];
+/// Convert bits to bytes, grouping them 8 by 8 because it's u8. The last
+/// byte is padded with zeros if the bits don't fill it.
+fn de_partition(bits: &[u8]) -> Vec<u8> {
+    bits.chunks(8)
+        .map(|byte| (to_byte(byte) << (8 - byte.len())) as u8)
+        .collect()
+}
 
-    for i in 0..16 {
-        bytes[i] = to_byte(&bits[8 * i..8 * (i + 1)]) as u8;
-    }
+#[inline]
+fn _generate(dict: &Dictionary, uuid: &Uuid) -> String {
+    let mut sentence = String::new();
+    _write_long(dict, uuid, &mut sentence).expect("writing to a String can't fail");
+    sentence
+}
 
-    bytes
+/// Write a sentence drawing its words straight from the lists shipped with
+/// the crate, so that not even the builtin `Dictionary` gets allocated.
+#[inline]
+fn write_builtin<W: fmt::Write>(layout: &[Part], uuid: &Uuid, out: &mut W) -> fmt::Result {
+    template::write_parts(layout, uuid.as_u128(), |c, i| data::list(c)[i], out)
 }
 
 #[inline]
-fn _generate(uuid: &Uuid) -> String {
-    // Convert the Uuid to an array of bytes
-    let uuid = uuid.as_bytes();
-    // Get the partition (it's basically random numbers (12) from the uuid)
-    let words = partition(&NORMAL, uuid);
-    // Generate the sentence and return it
-    format!(
-        "{} {} {} the {} of {} {} {} {} {} and {} {} {}",
-        NAMES[words[0]],
-        NAMES[words[1]],
-        NAMES[words[2]],
-        PERSONAL_NOUNS[words[3]],
-        PLACES[words[4]],
-        VERBS[words[5]],
-        NAMES[words[6]],
-        NAMES[words[7]],
-        NAMES[words[8]],
-        words[9],
-        ADJECTIVES[words[10]],
-        ANIMALS[words[11]]
-    )
+fn _write_long<W: fmt::Write>(dict: &Dictionary, uuid: &Uuid, out: &mut W) -> fmt::Result {
+    dict.long_template().write_uuid(uuid, out)
 }
 
 /// Create a long sentence using a new random UUID.
 ///
 /// Example of return: `Joy Bolt Kahler the avenger of Esbon jumped Carey Fatma Sander and 8 large ducks`
+#[cfg(feature = "random")]
 pub fn generate() -> String {
     // Generate a new Uuid using the v4 RFC
     let uuid = Uuid::new_v4();
 
     // Create the sentence from the Uuid
-    _generate(&uuid)
+    _generate(Dictionary::builtin(), &uuid)
+}
+
+/// Create a long sentence using a new time-ordered UUID (v7).
+///
+/// The UUIDs given back by `generate_inverse` sort in their creation order,
+/// the sentences themselves don't.
+///
+/// Example of return: `Marquez Siddra Roger the oil of Breaks borrowed Rhianon Ignatia Birkett and 2 clumsy crabs`
+#[cfg(feature = "v7")]
+pub fn generate_v7() -> String {
+    _generate(Dictionary::builtin(), &Uuid::now_v7())
+}
+
+/// Create a long sentence using the name-based UUID (v5) of `name` in
+/// `namespace`, the same name always giving the same sentence.
+///
+/// ```rust
+/// use uuid::Uuid;
+/// use uuid_readable_rs::generate_v5;
+///
+/// let sentence = generate_v5(&Uuid::NAMESPACE_DNS, b"example.com");
+/// assert_eq!(sentence, generate_v5(&Uuid::NAMESPACE_DNS, b"example.com"));
+/// ```
+#[cfg(feature = "v5")]
+pub fn generate_v5(namespace: &Uuid, name: &[u8]) -> String {
+    _generate(Dictionary::builtin(), &Uuid::new_v5(namespace, name))
 }
 
 /// Derive a long sentence from a UUID.
 ///
+/// The UUID is given as a `Uuid`, a `ReadableUuid` or, with the `uuid08`
+/// feature, a `Uuid` of the 0.8 version of the `uuid` crate, as are the ones
+/// of the other functions creating a sentence.
+///
 /// Example of return: `Joy Bolt Kahler the avenger of Esbon jumped Carey Fatma Sander and 8 large ducks`
-pub fn generate_from(uuid: Uuid) -> String {
+pub fn generate_from<U: Into<ReadableUuid>>(uuid: U) -> String {
     // Create the sentence from the Uuid
-    _generate(&uuid)
+    _generate(Dictionary::builtin(), uuid.into().as_uuid())
+}
+
+/// Write the long sentence of a UUID to `out`, as `generate_from` does but
+/// without allocating.
+///
+/// ```rust
+/// use std::fmt::Write;
+/// use uuid::Uuid;
+/// use uuid_readable_rs::write_long;
+///
+/// let uuid = Uuid::parse_str("0ee001c7-12f3-4b29-a4cc-f48838b3587a").unwrap();
+/// // The buffer can be reused across calls
+/// let mut line = String::with_capacity(128);
+/// write!(line, "user ").unwrap();
+/// write_long(&uuid, &mut line).unwrap();
+/// assert_eq!(line, "user Purdy Fusco Kask the loki of Manteo observed Barbe Lehet Pardew and 26 hard herons");
+/// ```
+pub fn write_long<W: fmt::Write>(uuid: &Uuid, out: &mut W) -> fmt::Result {
+    write_builtin(LONG_LAYOUT, uuid, out)
 }
 
 /// Get the original uuid from a sentence.
 ///
+/// The sentence is matched loosely: words can be separated by any amount of
+/// whitespace, surrounded by punctuation and written in any case. The
+/// connecting words (`the`, `of`, `and`) and any trailing words are ignored,
+/// see `generate_inverse_strict` for an exact match.
+///
+/// The checksum added by `generate_checked_from`, if any, is verified. It is
+/// recognized as the sentence being followed by exactly two words, `in` and
+/// a place: trailing words of that form are verified as a checksum as well.
+///
+/// Example of return: `0ee001c7-12f3-4b29-a4cc-f48838b3587a`
+pub fn generate_inverse<S: AsRef<str>>(sentence: S) -> Result<Uuid, ParseError> {
+    _inverse(Dictionary::builtin(), sentence.as_ref(), false)
+}
+
+/// Get the original uuid from a sentence, rejecting any sentence that
+/// `generate_from` could not have produced.
+///
+/// The words must be written exactly as generated and separated by a single
+/// space. This also checks the connecting words, that there are no extra
+/// words, that the number is written as generated and that each word fits in
+/// the bits of its slot. Hence every sentence accepted maps back to exactly
+/// one UUID. The checksum added by `generate_checked_from` is accepted and
+/// verified.
+///
 /// Example of return: `0ee001c7-12f3-4b29-a4cc-f48838b3587a`
-pub fn generate_inverse<S: AsRef<str>>(sentence: S) -> Result<Uuid> {
+pub fn generate_inverse_strict<S: AsRef<str>>(sentence: S) -> Result<Uuid, ParseError> {
+    _inverse(Dictionary::builtin(), sentence.as_ref(), true)
+}
+
+/// Get the original uuid from a sentence as a `Uuid` of the 0.8 version of
+/// the `uuid` crate, see `generate_inverse`.
+///
+/// ```rust
+/// use uuid_readable_rs::{generate_from, generate_inverse_08};
+///
+/// let uuid = uuid08::Uuid::parse_str("0ee001c7-12f3-4b29-a4cc-f48838b3587a").unwrap();
+/// assert_eq!(generate_inverse_08(generate_from(uuid)), Ok(uuid));
+/// ```
+#[cfg(feature = "uuid08")]
+pub fn generate_inverse_08<S: AsRef<str>>(sentence: S) -> Result<uuid08::Uuid, ParseError> {
+    generate_inverse(sentence).map(|uuid| ReadableUuid::from(uuid).into())
+}
+
+fn _inverse(dict: &Dictionary, sentence: &str, strict: bool) -> Result<Uuid, ParseError> {
+    let template = dict.long_template();
+    let expected = template.parts().len();
     // Split the sentence
-    let splitted: Vec<&str> = sentence.as_ref().split(' ').collect();
-    // Sanity check that we have enough values to work with
-    if splitted.len() < 15 {
-        return Err(anyhow!(
-            "The sentence does not correspond to a one from uuid-readable-rs."
-        ));
-    }
-    // Collect the index of each parts
-    let index_values = [
-        NAMES
-            .iter()
-            .position(|&r| r == splitted[0])
-            .context("NAMES (0) not found")? as u16,
-        NAMES
-            .iter()
-            .position(|&r| r == splitted[1])
-            .context("NAMES (1) not found")? as u16,
-        NAMES
-            .iter()
-            .position(|&r| r == splitted[2])
-            .context("NAMES (2) not found")? as u16,
-        PERSONAL_NOUNS
-            .iter()
-            .position(|&r| r == splitted[4])
-            .context("PERSONAL_NOUNS (4) not found")? as u16,
-        PLACES
-            .iter()
-            .position(|&r| r == splitted[6])
-            .context("PLACES (6) not found")? as u16,
-        VERBS
-            .iter()
-            .position(|&r| r == splitted[7])
-            .context("VERBS (7) not found")? as u16,
-        NAMES
-            .iter()
-            .position(|&r| r == splitted[8])
-            .context("NAMES (8) not found")? as u16,
-        NAMES
-            .iter()
-            .position(|&r| r == splitted[9])
-            .context("NAMES (9) not found")? as u16,
-        NAMES
-            .iter()
-            .position(|&r| r == splitted[10])
-            .context("NAMES (10) not found")? as u16,
-        splitted[12].parse::<u16>()?,
-        ADJECTIVES
-            .iter()
-            .position(|&r| r == splitted[13])
-            .context("ADJECTIVES (13) not found")? as u16,
-        ANIMALS
-            .iter()
-            .position(|&r| r == splitted[14])
-            .context("ANIMALS (14) not found")? as u16,
-    ];
-    // Convert the index into bits
-    let bits = to_bits_parted(&index_values);
-    // Convert the bits to bytes
-    let bytes = de_partition(&bits);
+    let splitted: Vec<&str> = if strict {
+        sentence.split(' ').collect()
+    } else {
+        split_words(sentence)
+    };
+    let (words, checksum_word) = split_checksum(dict, &splitted, expected, strict);
+    // Sanity check that we have enough values to work with, and nothing else
+    // than the checksum in strict mode
+    if words.len() < expected || (strict && words.len() != expected) {
+        return Err(ParseError::WordCount {
+            expected,
+            found: splitted.len(),
+        });
+    }
 
+    // The lenient version truncates the words that don't fit in their slot
+    let values = template.parse_words(&words[..expected], 0, strict, strict)?;
+    let bytes = template.assemble(&values);
     // Convert the bytes into the Uuid
-    Ok(Uuid::from_slice(&bytes)?)
+    let uuid = Uuid::from_slice(&bytes).expect("the long sentence holds 128 bits");
+
+    if let Some(word) = checksum_word {
+        let found = if strict {
+            dict.lookup(Category::Place, word)
+        } else {
+            dict.lookup_any_case(Category::Place, word)
+        };
+        match found {
+            Some(index) if index == checksum(&uuid) => {}
+            Some(_) => return Err(ParseError::ChecksumMismatch),
+            None => {
+                return Err(ParseError::UnknownWord {
+                    position: expected + 1,
+                    category: Category::Place,
+                })
+            }
+        }
+    }
+
+    Ok(uuid)
 }
 
-#[inline]
-fn _short(uuid: &Uuid) -> String {
-    // Convert the Uuid to an array of bytes
-    let uuid = uuid.as_bytes();
-    // Get the partition (it's basically random numbers (12) from the uuid)
-    let words = partition(&SHORT, uuid);
+/// Split the checksum off the words of a long sentence of `expected` words.
+///
+/// The checksum is only looked for as the last two words, so that the
+/// lenient decoder keeps ignoring the words following a sentence. It must
+/// then also be a place, `in` being a common word to follow a sentence with.
+fn split_checksum<'a, 'b>(
+    dict: &Dictionary,
+    words: &'b [&'a str],
+    expected: usize,
+    strict: bool,
+) -> (&'b [&'a str], Option<&'a str>) {
+    if words.len() != expected + 2 {
+        return (words, None);
+    }
+
+    let (sentence, checksum) = words.split_at(expected);
+    let checked = if strict {
+        checksum[0] == CHECKSUM_CONNECTOR
+    } else {
+        checksum[0].eq_ignore_ascii_case(CHECKSUM_CONNECTOR)
+            && dict.lookup_any_case(Category::Place, checksum[1]).is_some()
+    };
+    if checked {
+        (sentence, Some(checksum[1]))
+    } else {
+        (words, None)
+    }
+}
+
+/// Word introducing the checksum of a long sentence
+const CHECKSUM_CONNECTOR: &str = "in";
+
+/// Index of the place acting as the checksum of a UUID, the 13 bits of the
+/// 32-bit FNV-1a hash of its bytes folded together.
+fn checksum(uuid: &Uuid) -> usize {
+    let hash = uuid.as_bytes().iter().fold(0x811c_9dc5u32, |h, b| {
+        (h ^ *b as u32).wrapping_mul(0x0100_0193)
+    });
+
+    ((hash ^ hash >> 13 ^ hash >> 26) & 0x1fff) as usize
+}
 
-    // Generate the sentence and return it
+fn _generate_checked(dict: &Dictionary, uuid: &Uuid) -> String {
     format!(
-        "{} {} by {} {} {}",
-        NAMES[words[0]], VERBS[words[1]], words[2], ADJECTIVES[words[3]], ANIMALS[words[4]],
+        "{} {} {}",
+        _generate(dict, uuid),
+        CHECKSUM_CONNECTOR,
+        dict.word(Category::Place, checksum(uuid))
     )
 }
 
+/// Create a long sentence with a checksum using a new random UUID, see
+/// `generate_checked_from`.
+#[cfg(feature = "random")]
+pub fn generate_checked() -> String {
+    _generate_checked(Dictionary::builtin(), &Uuid::new_v4())
+}
+
+/// Derive a long sentence from a UUID, followed by a checksum: a place
+/// derived from a hash of the 128 bits.
+///
+/// `generate_inverse` checks it, so that a wrong but valid word is reported
+/// as a `ParseError::ChecksumMismatch` instead of giving another UUID.
+///
+/// ```rust
+/// use uuid::Uuid;
+/// use uuid_readable_rs::{generate_checked_from, generate_inverse, ParseError};
+///
+/// let uuid = Uuid::parse_str("0ee001c7-12f3-4b29-a4cc-f48838b3587a").unwrap();
+/// let sentence = generate_checked_from(uuid);
+/// assert_eq!(
+///     sentence,
+///     "Purdy Fusco Kask the loki of Manteo observed Barbe Lehet Pardew and 26 hard herons in Hughson"
+/// );
+/// assert_eq!(generate_inverse(&sentence), Ok(uuid));
+///
+/// let typo = sentence.replace("Purdy", "Fusco");
+/// assert_eq!(generate_inverse(&typo), Err(ParseError::ChecksumMismatch));
+/// ```
+pub fn generate_checked_from<U: Into<ReadableUuid>>(uuid: U) -> String {
+    _generate_checked(Dictionary::builtin(), uuid.into().as_uuid())
+}
+
+#[inline]
+fn _short(dict: &Dictionary, uuid: &Uuid) -> String {
+    let mut sentence = String::new();
+    _write_short(dict, uuid, &mut sentence).expect("writing to a String can't fail");
+    sentence
+}
+
+#[inline]
+fn _write_short<W: fmt::Write>(dict: &Dictionary, uuid: &Uuid, out: &mut W) -> fmt::Result {
+    dict.short_template().write_uuid(uuid, out)
+}
+
 /// Create a short sentence using a new random UUID.
 ///
 /// Example of return: `Alex sang by 60 narrow chickens`
+#[cfg(feature = "random")]
 pub fn short() -> String {
     // Generate a new Uuid using the v4 RFC
     let uuid = Uuid::new_v4();
 
     // Create the sentence from the Uuid
-    _short(&uuid)
+    _short(Dictionary::builtin(), &uuid)
 }
 
 /// Derive a short sentence from a UUID.
 ///
 /// Example of return: `Alex sang by 60 narrow chickens`
-pub fn short_from(uuid: Uuid) -> String {
+pub fn short_from<U: Into<ReadableUuid>>(uuid: U) -> String {
     // Create the sentence from the Uuid
-    _short(&uuid)
+    _short(Dictionary::builtin(), uuid.into().as_uuid())
+}
+
+/// Write the short sentence of a UUID to `out`, as `short_from` does but
+/// without allocating.
+pub fn write_short<W: fmt::Write>(uuid: &Uuid, out: &mut W) -> fmt::Result {
+    write_builtin(SHORT_LAYOUT, uuid, out)
+}
+
+/// Get the first 32 bits of the original uuid from a short sentence.
+///
+/// The sentence is matched loosely, as with `generate_inverse`. As the short
+/// sentence only holds the first 32 bits of the UUID, the
+/// complete UUID can't be recovered, only its prefix.
+///
+/// Example of return: `0x0ee001c7`
+pub fn short_inverse<S: AsRef<str>>(sentence: S) -> Result<u32, ParseError> {
+    _short_inverse(Dictionary::builtin(), sentence.as_ref())
+}
+
+fn _short_inverse(dict: &Dictionary, sentence: &str) -> Result<u32, ParseError> {
+    // Only the first 2**bits words of each list can be used by the short version
+    let bytes = dict.short_template().decode(sentence)?;
+
+    // Pack the 32 bits back together
+    Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+/// Check if a short sentence could have been derived from a UUID.
+///
+/// Only the first 32 bits of the UUID are compared, see `short_inverse`.
+pub fn short_matches<U: Into<ReadableUuid>, S: AsRef<str>>(uuid: U, sentence: S) -> bool {
+    let uuid = uuid.into();
+    let bytes = uuid.as_uuid().as_bytes();
+    let prefix = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+
+    matches!(short_inverse(sentence), Ok(p) if p == prefix)
+}
+
+#[inline]
+fn _medium(dict: &Dictionary, id: u64) -> String {
+    dict.medium_template().encode(&id.to_be_bytes())
+}
+
+/// Derive a medium sentence from a 64-bit id.
+///
+/// Example of return: `Katharyn the minister of Mosinee trotted cute hogs`
+pub fn medium_from(id: u64) -> String {
+    _medium(Dictionary::builtin(), id)
+}
+
+/// Get the original id from a medium sentence.
+///
+/// The sentence is matched loosely, as with `generate_inverse`, but each
+/// word must be one `medium_from` could have produced. Hence the medium
+/// sentence and the id form a bijection.
+///
+/// Example of return: `0x0ee001c712f34b29`
+pub fn medium_inverse<S: AsRef<str>>(sentence: S) -> Result<u64, ParseError> {
+    _medium_inverse(Dictionary::builtin(), sentence.as_ref())
+}
+
+fn _medium_inverse(dict: &Dictionary, sentence: &str) -> Result<u64, ParseError> {
+    let bytes = dict.medium_template().decode(sentence)?;
+
+    let mut id = [0; 8];
+    id.copy_from_slice(&bytes);
+    Ok(u64::from_be_bytes(id))
+}
+
+/// Mask of the 62 bits following the variant of a UUID
+const LOW_BITS: u128 = (1 << 62) - 1;
+
+/// Drop the version and variant bits of a v4 UUID, the 122 bits left being
+/// shifted to the most significant ones.
+fn strip_v4(uuid: &Uuid) -> Result<[u8; 16], EncodeError> {
+    if uuid.get_version() != Some(uuid::Version::Random)
+        || uuid.get_variant() != uuid::Variant::RFC4122
+    {
+        return Err(EncodeError::NotV4(*uuid));
+    }
+
+    let bits = uuid.as_u128();
+    // 48 bits, the version (4 bits), 12 bits, the variant (2 bits), 62 bits
+    let random = (bits >> 80) << 74 | ((bits >> 64) & 0xfff) << 62 | (bits & LOW_BITS);
+    Ok((random << 6).to_be_bytes())
+}
+
+/// Put back the version and variant bits stripped by `strip_v4`
+fn restore_v4(bytes: &[u8]) -> Uuid {
+    let mut random = [0; 16];
+    random.copy_from_slice(bytes);
+    let random = u128::from_be_bytes(random) >> 6;
+
+    let bits = (random >> 74) << 80
+        | 0x4 << 76
+        | ((random >> 62) & 0xfff) << 64
+        | 0b10 << 62
+        | (random & LOW_BITS);
+    Uuid::from_u128(bits)
+}
+
+fn _compact(dict: &Dictionary, uuid: &Uuid) -> Result<String, EncodeError> {
+    Ok(dict.compact_template().encode(&strip_v4(uuid)?))
+}
+
+/// Create a compact sentence using a new random UUID.
+///
+/// Example of return: `Mara Cherice Light the defiler of Pennellville learned Paddie Lotte and 1 hard herons`
+#[cfg(feature = "random")]
+pub fn generate_compact() -> String {
+    _compact(Dictionary::builtin(), &Uuid::new_v4()).expect("new_v4 gives v4 UUIDs")
+}
+
+/// Derive a compact sentence from a v4 UUID.
+///
+/// The version and variant bits being the same for every v4 UUID, only
+/// the 122 random bits are kept, which takes one word less than
+/// `generate_from`. Any other version of UUID gives an `EncodeError`.
+///
+/// Example of return: `Mara Cherice Light the defiler of Pennellville learned Paddie Lotte and 1 hard herons`
+pub fn generate_compact_from<U: Into<ReadableUuid>>(uuid: U) -> Result<String, EncodeError> {
+    _compact(Dictionary::builtin(), uuid.into().as_uuid())
+}
+
+/// Get the original v4 UUID from a compact sentence.
+///
+/// The sentence is matched loosely, as with `medium_inverse`, and the version
+/// and variant bits are put back.
+pub fn generate_compact_inverse<S: AsRef<str>>(sentence: S) -> Result<Uuid, ParseError> {
+    _compact_inverse(Dictionary::builtin(), sentence.as_ref())
+}
+
+fn _compact_inverse(dict: &Dictionary, sentence: &str) -> Result<Uuid, ParseError> {
+    let bytes = dict.compact_template().decode(sentence)?;
+    Ok(restore_v4(&bytes))
+}
+
+/// Number of bytes held by each clause of `encode_bytes`, one medium sentence
+const CLAUSE_BYTES: usize = 8;
+
+/// Number of words of each clause of `encode_bytes`
+const CLAUSE_WORDS: usize = MEDIUM_LAYOUT.len();
+
+fn _encode_bytes(dict: &Dictionary, bytes: &[u8]) -> String {
+    let template = dict.medium_template();
+    let mut clauses: Vec<String> = Vec::with_capacity(bytes.len() / CLAUSE_BYTES + 2);
+
+    for chunk in bytes.chunks(CLAUSE_BYTES) {
+        // The last clause is padded with zeros
+        let mut clause = [0; CLAUSE_BYTES];
+        clause[..chunk.len()].copy_from_slice(chunk);
+        clauses.push(template.encode(&clause));
+    }
+    let padding = (CLAUSE_BYTES - bytes.len() % CLAUSE_BYTES) % CLAUSE_BYTES;
+    if padding > 0 {
+        clauses.push(format!("padded with {}", padding));
+    }
+
+    clauses.join(", ")
+}
+
+/// Create a sentence from any bytes, such as a digest or a database key.
+///
+/// The bytes are split in clauses of 8 bytes, each written as a medium
+/// sentence and separated by a comma. When the length isn't a multiple of 8,
+/// the last clause is padded with zeros and the sentence ends with the number
+/// of bytes added, e.g. `padded with 5`. No bytes gives an empty sentence.
+///
+/// ```rust
+/// use uuid_readable_rs::{decode_bytes, encode_bytes};
+///
+/// let sentence = encode_bytes(b"key");
+/// assert_eq!(sentence, "Grete the bibliographer of Katy suspended mysterious rooks, padded with 5");
+/// assert_eq!(decode_bytes(&sentence).unwrap(), b"key");
+/// ```
+pub fn encode_bytes<B: AsRef<[u8]>>(bytes: B) -> String {
+    _encode_bytes(Dictionary::builtin(), bytes.as_ref())
+}
+
+/// Get the original bytes from a sentence created by `encode_bytes`.
+///
+/// The sentence is matched loosely, as with `medium_inverse`. The padding
+/// must be between 1 and 7 bytes, all zeros, hence the sentence and the bytes
+/// form a bijection.
+pub fn decode_bytes<S: AsRef<str>>(sentence: S) -> Result<Vec<u8>, ParseError> {
+    _decode_bytes(Dictionary::builtin(), sentence.as_ref())
+}
+
+fn _decode_bytes(dict: &Dictionary, sentence: &str) -> Result<Vec<u8>, ParseError> {
+    let splitted: Vec<&str> = split_words(sentence);
+    let (words, padding) = match splitted.len() % CLAUSE_WORDS {
+        0 => (&splitted[..], 0),
+        3 => {
+            let (words, tail) = splitted.split_at(splitted.len() - 3);
+            let position = words.len();
+            if !tail[0].eq_ignore_ascii_case("padded") || !tail[1].eq_ignore_ascii_case("with") {
+                return Err(ParseError::Padding { position });
+            }
+            match parse_number(tail[2], position + 2)? as usize {
+                // Only the last clause is padded
+                padding if padding > 0 && padding < CLAUSE_BYTES && !words.is_empty() => {
+                    (words, padding)
+                }
+                _ => return Err(ParseError::Padding { position }),
+            }
+        }
+        rest => {
+            return Err(ParseError::WordCount {
+                expected: splitted.len() - rest,
+                found: splitted.len(),
+            })
+        }
+    };
+
+    let template = dict.medium_template();
+    let mut bytes = Vec::with_capacity(words.len() / CLAUSE_WORDS * CLAUSE_BYTES);
+    for (i, clause) in words.chunks(CLAUSE_WORDS).enumerate() {
+        let values = template.parse_words(clause, i * CLAUSE_WORDS, false, true)?;
+        bytes.extend(template.assemble(&values));
+    }
+
+    // Drop the padding, which must not hide any data
+    let len = bytes.len() - padding;
+    if bytes[len..].iter().any(|b| *b != 0) {
+        return Err(ParseError::Padding {
+            position: words.len(),
+        });
+    }
+    bytes.truncate(len);
+
+    Ok(bytes)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::string::ToString;
+    use alloc::vec;
+    use data::{
+        adjectives::ADJECTIVES, animals::ANIMALS, names::NAMES, personal_nouns::PERSONAL_NOUNS,
+        places::PLACES, verbs::VERBS,
+    };
 
     #[test]
     fn test_adjectives_sanity() {
@@ -421,6 +1067,27 @@ mod tests {
         assert_eq!(original_length, final_length);
     }
 
+    #[test]
+    fn test_indexes() {
+        let categories = [
+            Category::Name,
+            Category::PersonalNoun,
+            Category::Place,
+            Category::Verb,
+            Category::Adjective,
+            Category::Animal,
+        ];
+
+        for category in categories.iter() {
+            let list = data::list(*category);
+            let words = Dictionary::builtin().list(*category);
+            assert_eq!(list.len(), words.len());
+            for (i, w) in list.iter().enumerate() {
+                assert_eq!(words.position(w), Some(i));
+            }
+        }
+    }
+
     #[test]
     fn test_generate() {
         let uuid = Uuid::parse_str("0ee001c7-12f3-4b29-a4cc-f48838b3587a").unwrap();
@@ -443,8 +1110,452 @@ mod tests {
     #[test]
     fn test_inverse() {
         let uuid = Uuid::parse_str("0ee001c7-12f3-4b29-a4cc-f48838b3587a").unwrap();
-        let i = generate_inverse(&generate_from(uuid)).unwrap();
+        let i = generate_inverse(generate_from(uuid)).unwrap();
+        assert_eq!(i, uuid);
+    }
+
+    #[test]
+    fn test_short_inverse() {
+        let uuid = Uuid::parse_str("0ee001c7-12f3-4b29-a4cc-f48838b3587a").unwrap();
+        let i = short_inverse(short_from(uuid)).unwrap();
+        assert_eq!(i, 0x0ee001c7);
+
+        let uuid = Uuid::parse_str("00000000-0000-0000-0000-000000000000").unwrap();
+        assert_eq!(short_inverse(short_from(uuid)).unwrap(), 0);
+
+        let uuid = Uuid::parse_str("ffffffff-ffff-ffff-ffff-ffffffffffff").unwrap();
+        assert_eq!(short_inverse(short_from(uuid)).unwrap(), u32::MAX);
+    }
+
+    #[test]
+    fn test_short_matches() {
+        let uuid = Uuid::parse_str("0ee001c7-12f3-4b29-a4cc-f48838b3587a").unwrap();
+        assert!(short_matches(uuid, "Egidius filled by 0 calm hawks"));
+
+        // Same 32 bits prefix, different UUID
+        let other = Uuid::parse_str("0ee001c7-0000-0000-0000-000000000000").unwrap();
+        assert!(short_matches(other, "Egidius filled by 0 calm hawks"));
+
+        let other = Uuid::parse_str("1ee001c7-12f3-4b29-a4cc-f48838b3587a").unwrap();
+        assert!(!short_matches(other, "Egidius filled by 0 calm hawks"));
+    }
+
+    #[test]
+    fn test_bad_short_inverse() {
+        // Valid name, but outside of the 6 bits used by the short version
+        assert!(short_inverse("Purdy filled by 0 calm hawks").is_err());
+        assert!(short_inverse("Egidius filled by").is_err());
+    }
+
+    #[test]
+    fn test_medium() {
+        let m = medium_from(0x0ee001c712f34b29);
+        assert_eq!(m, "Mara the owe of Frankenmuth appears motionless lions");
+        assert_eq!(medium_inverse(&m), Ok(0x0ee001c712f34b29));
+
+        for id in &[0, 1, u64::MAX, 1 << 63, 0x0123456789abcdef] {
+            assert_eq!(medium_inverse(medium_from(*id)), Ok(*id));
+        }
+        assert_eq!(
+            medium_inverse("mara, the owe of frankenmuth APPEARS motionless lions."),
+            Ok(0x0ee001c712f34b29)
+        );
+    }
+
+    #[test]
+    fn test_bad_medium_inverse() {
+        // Valid adjective, but outside of the 7 bits used by the medium version
+        assert_eq!(
+            medium_inverse("Mara the owe of Frankenmuth appears jealous lions"),
+            Err(ParseError::UnknownWord {
+                position: 6,
+                category: Category::Adjective
+            })
+        );
+        assert!(medium_inverse("Mara the owe of Frankenmuth").is_err());
+    }
+
+    #[test]
+    fn test_bytes() {
+        let digest: Vec<u8> = (0..32u8).map(|i| i.wrapping_mul(97)).collect();
+        for len in 0..digest.len() {
+            let sentence = encode_bytes(&digest[..len]);
+            assert_eq!(
+                decode_bytes(&sentence),
+                Ok(digest[..len].to_vec()),
+                "{}",
+                len
+            );
+        }
+
+        assert_eq!(encode_bytes([]), "");
+        assert_eq!(
+            encode_bytes(0x0ee001c712f34b29u64.to_be_bytes()),
+            "Mara the owe of Frankenmuth appears motionless lions"
+        );
+        assert_eq!(
+            encode_bytes([0; 9]),
+            "Fusco the muleteer of Katy suspended mysterious rooks, \
+             Fusco the muleteer of Katy suspended mysterious rooks, padded with 7"
+        );
+    }
+
+    #[test]
+    fn test_bad_decode_bytes() {
+        let clause = "Fusco the muleteer of Katy suspended mysterious rooks";
+        // Padding out of range, or without any clause
+        for padding in &["padded with 0", "padded with 8", "padding with 3"] {
+            assert_eq!(
+                decode_bytes(format!("{}, {}", clause, padding)),
+                Err(ParseError::Padding { position: 8 })
+            );
+        }
+        assert_eq!(
+            decode_bytes("padded with 3"),
+            Err(ParseError::Padding { position: 0 })
+        );
+        // The padded bytes are not zeros
+        let sentence = encode_bytes([1, 2, 3, 4]).replace("with 4", "with 5");
+        assert_eq!(
+            decode_bytes(sentence),
+            Err(ParseError::Padding { position: 8 })
+        );
+        assert_eq!(
+            decode_bytes(format!("{}, Fusco the", clause)),
+            Err(ParseError::WordCount {
+                expected: 8,
+                found: 10
+            })
+        );
+        assert_eq!(
+            decode_bytes(format!(
+                "{}, Fusco the muleteer of Katy suspended mysterious unicorns",
+                clause
+            )),
+            Err(ParseError::UnknownWord {
+                position: 15,
+                category: Category::Animal
+            })
+        );
+    }
+
+    #[test]
+    fn test_compact() {
+        let uuid = Uuid::parse_str("0ee001c7-12f3-4b29-a4cc-f48838b3587a").unwrap();
+        let c = generate_compact_from(uuid).unwrap();
+        assert_eq!(
+            c,
+            "Mara Cherice Light the defiler of Pennellville learned Paddie Lotte and 1 hard herons"
+        );
+        assert_eq!(generate_compact_inverse(&c), Ok(uuid));
+
+        // Every random bit set, or none
+        for uuid in &[
+            "00000000-0000-4000-8000-000000000000",
+            "ffffffff-ffff-4fff-bfff-ffffffffffff",
+        ] {
+            let uuid = Uuid::parse_str(uuid).unwrap();
+            let c = generate_compact_from(uuid).unwrap();
+            assert_eq!(generate_compact_inverse(&c), Ok(uuid));
+        }
+    }
+
+    #[cfg(feature = "random")]
+    #[test]
+    fn test_compact_random() {
+        for _ in 0..100 {
+            let c = generate_compact();
+            let uuid = generate_compact_inverse(&c).unwrap();
+            assert_eq!(uuid.get_version_num(), 4);
+            assert_eq!(generate_compact_from(uuid), Ok(c));
+        }
+    }
+
+    #[test]
+    fn test_compact_not_v4() {
+        for uuid in &[
+            Uuid::nil(),
+            Uuid::from_bytes([0xff; 16]),
+            // Version 1
+            Uuid::parse_str("0ee001c7-12f3-1b29-a4cc-f48838b3587a").unwrap(),
+            // Version 4, but not the RFC variant
+            Uuid::parse_str("0ee001c7-12f3-4b29-e4cc-f48838b3587a").unwrap(),
+        ] {
+            assert_eq!(generate_compact_from(*uuid), Err(EncodeError::NotV4(*uuid)));
+        }
+    }
+
+    #[test]
+    fn test_checked() {
+        let uuid = Uuid::parse_str("0ee001c7-12f3-4b29-a4cc-f48838b3587a").unwrap();
+        let sentence = generate_checked_from(uuid);
+        assert_eq!(generate_inverse(&sentence), Ok(uuid));
+        assert_eq!(generate_inverse_strict(&sentence), Ok(uuid));
+        assert_eq!(
+            generate_inverse(sentence.to_lowercase() + " today"),
+            Ok(uuid)
+        );
+        // The unchecked form still decodes
+        assert_eq!(generate_inverse(generate_from(uuid)), Ok(uuid));
+    }
+
+    #[cfg(feature = "random")]
+    #[test]
+    fn test_checked_random() {
+        for _ in 0..100 {
+            let sentence = generate_checked();
+            assert!(generate_inverse_strict(&sentence).is_ok(), "{}", sentence);
+        }
+    }
+
+    #[test]
+    fn test_checksum_trailing_words() {
+        let uuid = Uuid::parse_str("0ee001c7-12f3-4b29-a4cc-f48838b3587a").unwrap();
+        let sentence = generate_from(uuid);
+        // Not a place, or not the last two words: not a checksum
+        for trailing in &[
+            " in production",
+            " in Atlantis",
+            " in production today",
+            " in Hughson today",
+            " deployed in Manteo",
+        ] {
+            assert_eq!(
+                generate_inverse(sentence.clone() + trailing),
+                Ok(uuid),
+                "{}",
+                trailing
+            );
+        }
+
+        // A place right after `in` is verified
+        assert_eq!(generate_inverse(sentence.clone() + " in Hughson"), Ok(uuid));
+        assert_eq!(
+            generate_inverse(sentence + " in Manteo"),
+            Err(ParseError::ChecksumMismatch)
+        );
+    }
+
+    #[test]
+    fn test_checksum_mismatch() {
+        let sentence =
+            "Fusco Fusco Kask the loki of Manteo observed Barbe Lehet Pardew and 26 hard herons in Hughson";
+        assert_eq!(
+            generate_inverse(sentence),
+            Err(ParseError::ChecksumMismatch)
+        );
+        assert_eq!(
+            generate_inverse_strict(sentence),
+            Err(ParseError::ChecksumMismatch)
+        );
+        assert_eq!(
+            ParseError::ChecksumMismatch.to_string(),
+            "checksum mismatch"
+        );
+
+        let sentence =
+            "Purdy Fusco Kask the loki of Manteo observed Barbe Lehet Pardew and 26 hard herons in Atlantis";
+        assert_eq!(
+            generate_inverse_strict(sentence),
+            Err(ParseError::UnknownWord {
+                position: 16,
+                category: Category::Place
+            })
+        );
+
+        // Nothing is allowed after the checksum in strict mode
+        let sentence =
+            "Purdy Fusco Kask the loki of Manteo observed Barbe Lehet Pardew and 26 hard herons in Hughson today";
+        assert_eq!(
+            generate_inverse_strict(sentence),
+            Err(ParseError::WordCount {
+                expected: 15,
+                found: 18
+            })
+        );
+    }
+
+    #[test]
+    fn test_inverse_strict() {
+        let uuid = Uuid::parse_str("0ee001c7-12f3-4b29-a4cc-f48838b3587a").unwrap();
+        let i = generate_inverse_strict(generate_from(uuid)).unwrap();
         assert_eq!(i, uuid);
+
+        for uuid in &[Uuid::nil(), Uuid::from_bytes([0xff; 16])] {
+            assert_eq!(
+                generate_inverse_strict(generate_from(*uuid)).unwrap(),
+                *uuid
+            );
+        }
+    }
+
+    #[test]
+    fn test_bad_inverse_strict() {
+        let sentences = [
+            // Wrong connecting words
+            "Purdy Fusco Kask a loki of Manteo observed Barbe Lehet Pardew and 26 hard herons",
+            "Purdy Fusco Kask the loki in Manteo observed Barbe Lehet Pardew and 26 hard herons",
+            "Purdy Fusco Kask the loki of Manteo observed Barbe Lehet Pardew with 26 hard herons",
+            // Extra trailing words
+            "Purdy Fusco Kask the loki of Manteo observed Barbe Lehet Pardew and 26 hard herons today",
+            // Number out of the 5 bits or not written as generated
+            "Purdy Fusco Kask the loki of Manteo observed Barbe Lehet Pardew and 99 hard herons",
+            "Purdy Fusco Kask the loki of Manteo observed Barbe Lehet Pardew and 026 hard herons",
+            "Purdy Fusco Kask the loki of Manteo observed Barbe Lehet Pardew and +26 hard herons",
+            // A 14-bit name in the 11-bit slot
+            "Purdy Katharyn Kask the loki of Manteo observed Barbe Lehet Pardew and 26 hard herons",
+            // An adjective outside of the 6 bits
+            "Purdy Fusco Kask the loki of Manteo observed Barbe Lehet Pardew and 26 jealous herons",
+        ];
+
+        for sentence in sentences.iter() {
+            assert!(generate_inverse_strict(sentence).is_err(), "{}", sentence);
+            // The lenient version still accepts them
+            assert!(generate_inverse(sentence).is_ok(), "{}", sentence);
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let rev = generate_inverse("Purdy Fusco Kask the loki");
+        assert_eq!(
+            rev,
+            Err(ParseError::WordCount {
+                expected: 15,
+                found: 5
+            })
+        );
+
+        let rev = generate_inverse(
+            "Purdy Fusco Kask the loki of Manteo observed Barbe Lehet Pardew and 26 hard unicorns",
+        );
+        assert_eq!(
+            rev,
+            Err(ParseError::UnknownWord {
+                position: 14,
+                category: Category::Animal
+            })
+        );
+
+        let rev = generate_inverse(
+            "Purdy Fusco Kask the loki of Manteo observed Barbe Lehet Pardew and many hard herons",
+        );
+        assert_eq!(rev, Err(ParseError::InvalidNumber { position: 12 }));
+
+        let rev = generate_inverse_strict(
+            "Purdy Fusco Kask the loki of Manteo observed Barbe Lehet Pardew and 99 hard herons",
+        );
+        assert_eq!(
+            rev,
+            Err(ParseError::NumberOutOfRange {
+                position: 12,
+                max: 31
+            })
+        );
+
+        let rev = generate_inverse_strict(
+            "Purdy Fusco Kask the loki in Manteo observed Barbe Lehet Pardew and 26 hard herons",
+        );
+        assert_eq!(
+            rev,
+            Err(ParseError::Connector {
+                position: 5,
+                expected: "of".to_string()
+            })
+        );
+        assert_eq!(
+            rev.unwrap_err().to_string(),
+            "expected \"of\" at position 5"
+        );
+    }
+
+    #[test]
+    fn test_inverse_normalized() {
+        let uuid = Uuid::parse_str("0ee001c7-12f3-4b29-a4cc-f48838b3587a").unwrap();
+        let sentences = [
+            "purdy fusco kask the loki of manteo observed barbe lehet pardew and 26 hard herons",
+            "PURDY FUSCO KASK THE LOKI OF MANTEO OBSERVED BARBE LEHET PARDEW AND 26 HARD HERONS",
+            "Purdy Fusco Kask  the loki of Manteo observed Barbe Lehet Pardew and 26 hard herons\n",
+            "\tPurdy Fusco Kask\tthe loki of Manteo\r\nobserved Barbe Lehet Pardew and 26 hard herons",
+            "Purdy\u{a0}Fusco Kask the loki of Manteo observed Barbe Lehet Pardew and 26 hard herons",
+            "\"Purdy Fusco Kask, the loki of Manteo, observed Barbe Lehet Pardew and 26 hard herons.\"",
+            "- Purdy Fusco Kask the loki of Manteo observed Barbe Lehet Pardew and 26 hard herons !",
+        ];
+
+        for sentence in sentences.iter() {
+            assert_eq!(generate_inverse(sentence), Ok(uuid), "{}", sentence);
+            // Only the exact sentence is accepted in strict mode
+            assert!(generate_inverse_strict(sentence).is_err(), "{}", sentence);
+        }
+
+        assert_eq!(
+            short_inverse(" egidius  FILLED by 0 calm hawks.\n"),
+            Ok(0x0ee001c7)
+        );
+    }
+
+    #[test]
+    fn test_inverse_case_collision() {
+        // Both JoAnn and Joann are names, joann can't be decoded
+        let sentence =
+            "JoAnn Fusco Kask the loki of Manteo observed Barbe Lehet Pardew and 26 hard herons";
+        assert!(generate_inverse(sentence).is_ok());
+        assert_eq!(
+            generate_inverse(sentence.to_lowercase()),
+            Err(ParseError::Ambiguous { candidates: 2 })
+        );
+
+        // A trailing underscore is part of the name, not punctuation
+        assert_eq!(split_words("Jaime_ the"), vec!["Jaime_", "the"]);
+    }
+
+    /// Fixed buffer, writing past its end fails
+    struct Buffer {
+        bytes: [u8; 128],
+        len: usize,
+    }
+
+    impl fmt::Write for Buffer {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let end = self.len + s.len();
+            self.bytes
+                .get_mut(self.len..end)
+                .ok_or(fmt::Error)?
+                .copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    impl Buffer {
+        fn as_str(&self) -> &str {
+            core::str::from_utf8(&self.bytes[..self.len]).unwrap()
+        }
+    }
+
+    #[test]
+    fn test_write() {
+        for i in 0..100u128 {
+            let uuid = Uuid::from_u128(i.wrapping_mul(0x9e37_79b9_7f4a_7c15_f39c_c060_5ced_c835));
+
+            let mut buffer = Buffer {
+                bytes: [0; 128],
+                len: 0,
+            };
+            write_long(&uuid, &mut buffer).unwrap();
+            assert_eq!(buffer.as_str(), generate_from(uuid));
+
+            buffer.len = 0;
+            write_short(&uuid, &mut buffer).unwrap();
+            assert_eq!(buffer.as_str(), short_from(uuid));
+        }
+
+        // The error of the writer is passed on
+        let mut buffer = Buffer {
+            bytes: [0; 128],
+            len: 120,
+        };
+        assert!(write_long(&Uuid::nil(), &mut buffer).is_err());
     }
 
     #[test]
@@ -457,6 +1568,17 @@ mod tests {
         assert_eq!(byte, 41);
     }
 
+    #[cfg(feature = "v7")]
+    #[test]
+    fn test_generate_v7() {
+        let a = generate_inverse_strict(generate_v7()).unwrap();
+        let b = generate_inverse_strict(generate_v7()).unwrap();
+        assert_eq!(a.get_version_num(), 7);
+        assert_eq!(b.get_version_num(), 7);
+        // Created in order, and distinct within the same millisecond
+        assert!(a < b);
+    }
+
     #[test]
     fn test_compatibility() {
         let uuid = Uuid::parse_str("00000000-0000-0000-0000-000000000000").unwrap();
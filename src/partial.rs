@@ -0,0 +1,201 @@
+use uuid::Uuid;
+
+use crate::{parse_number, split_blanks, Dictionary, ParseError, Part};
+
+/// The UUIDs consistent with a partial sentence, see `generate_inverse_partial`.
+///
+/// A UUID matches if its bits selected by `mask()` are the ones of `value()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PartialMatch {
+    mask: u128,
+    value: u128,
+}
+
+impl PartialMatch {
+    /// The bits of the UUID known from the sentence
+    pub fn mask(&self) -> u128 {
+        self.mask
+    }
+
+    /// The value of the known bits, the others being 0
+    pub fn value(&self) -> u128 {
+        self.value
+    }
+
+    /// Number of bits known from the sentence
+    pub fn known_bits(&self) -> u32 {
+        self.mask.count_ones()
+    }
+
+    /// True if every bit is known, the sentence giving a single UUID
+    pub fn is_complete(&self) -> bool {
+        self.mask == u128::MAX
+    }
+
+    /// Smallest matching UUID, every unknown bit being 0
+    pub fn lower(&self) -> Uuid {
+        Uuid::from_u128(self.value)
+    }
+
+    /// Greatest matching UUID, every unknown bit being 1
+    pub fn upper(&self) -> Uuid {
+        Uuid::from_u128(self.value | !self.mask)
+    }
+
+    /// True if the UUID is consistent with the sentence
+    pub fn matches(&self, uuid: &Uuid) -> bool {
+        uuid.as_u128() & self.mask == self.value
+    }
+}
+
+/// Get the UUIDs consistent with the beginning of a long sentence, or with a
+/// sentence whose unknown words are replaced by `?` or `*`.
+///
+/// The words are matched loosely, as with `generate_inverse`, but each must
+/// fit in the bits of its slot. The words past the end of the sentence are
+/// unknown, and any trailing words are ignored.
+///
+/// Every matching UUID is between `lower()` and `upper()`, which can be used
+/// for a range query. When a word in the middle is unknown, the range also
+/// holds UUIDs not matching the sentence, `matches()` telling them apart.
+///
+/// ```rust
+/// use uuid::Uuid;
+/// use uuid_readable_rs::generate_inverse_partial;
+///
+/// let partial = generate_inverse_partial("Purdy Fusco Kask the loki of Manteo").unwrap();
+/// assert_eq!(partial.known_bits(), 63);
+/// assert_eq!(partial.lower().to_string(), "0ee001c7-12f3-4b28-0000-000000000000");
+/// assert_eq!(partial.upper().to_string(), "0ee001c7-12f3-4b29-ffff-ffffffffffff");
+///
+/// let uuid = Uuid::parse_str("0ee001c7-12f3-4b29-a4cc-f48838b3587a").unwrap();
+/// assert!(partial.matches(&uuid));
+/// ```
+pub fn generate_inverse_partial<S: AsRef<str>>(sentence: S) -> Result<PartialMatch, ParseError> {
+    _inverse_partial(Dictionary::builtin(), sentence.as_ref())
+}
+
+pub(crate) fn _inverse_partial(
+    dict: &Dictionary,
+    sentence: &str,
+) -> Result<PartialMatch, ParseError> {
+    let template = dict.long_template();
+    let words = split_blanks(sentence);
+
+    let mut partial = PartialMatch { mask: 0, value: 0 };
+    // Bits of the UUID left after the current slot
+    let mut remaining = template.bits();
+    for (position, (part, word)) in template.parts().iter().zip(words).enumerate() {
+        let bits = u32::from(part.bits());
+        remaining -= bits;
+        let value = match (part, word) {
+            // The connecting words are ignored, and so are the unknown words
+            (Part::Literal(_), _) | (_, None) => continue,
+            (Part::Word { category, .. }, Some(word)) => {
+                match dict.lookup_any_case(*category, word) {
+                    Some(i) if i < 1 << bits => i as u128,
+                    Some(_) => {
+                        return Err(ParseError::UnknownWord {
+                            position,
+                            category: *category,
+                        })
+                    }
+                    None => return Err(dict.unknown_word(*category, word, position)),
+                }
+            }
+            (Part::Number { .. }, Some(word)) => {
+                let number = parse_number(word, position)?;
+                if u32::from(number) >= 1 << bits {
+                    return Err(ParseError::NumberOutOfRange {
+                        position,
+                        max: ((1u32 << bits) - 1) as u16,
+                    });
+                }
+                number as u128
+            }
+        };
+
+        partial.mask |= ((1 << bits) - 1) << remaining;
+        partial.value |= value << remaining;
+    }
+
+    Ok(partial)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    const SENTENCE: &str =
+        "Purdy Fusco Kask the loki of Manteo observed Barbe Lehet Pardew and 26 hard herons";
+
+    fn uuid() -> Uuid {
+        Uuid::parse_str("0ee001c7-12f3-4b29-a4cc-f48838b3587a").unwrap()
+    }
+
+    #[test]
+    fn test_partial_complete() {
+        let partial = generate_inverse_partial(SENTENCE).unwrap();
+        assert!(partial.is_complete());
+        assert_eq!(partial.lower(), uuid());
+        assert_eq!(partial.upper(), uuid());
+    }
+
+    #[test]
+    fn test_partial_prefix() {
+        let words: Vec<&str> = SENTENCE.split(' ').collect();
+        let mut known = 0;
+        for len in 0..=words.len() {
+            let partial = generate_inverse_partial(words[..len].join(" ")).unwrap();
+            assert!(partial.known_bits() >= known);
+            known = partial.known_bits();
+            // A prefix gives the tightest range
+            assert_eq!(
+                partial.upper().as_u128() - partial.lower().as_u128(),
+                !partial.mask()
+            );
+            assert!(partial.matches(&uuid()));
+            assert!(partial.lower() <= uuid() && uuid() <= partial.upper());
+        }
+
+        let partial = generate_inverse_partial("").unwrap();
+        assert_eq!(partial.lower(), Uuid::nil());
+        assert_eq!(partial.upper(), Uuid::from_u128(u128::MAX));
+    }
+
+    #[test]
+    fn test_partial_blanks() {
+        let partial = generate_inverse_partial(
+            "Purdy * Kask the loki of ? observed Barbe Lehet Pardew and 26 hard ?",
+        )
+        .unwrap();
+        assert_eq!(partial.known_bits(), 128 - 11 - 13 - 7);
+        assert!(partial.matches(&uuid()));
+        assert!(partial.lower() <= uuid() && uuid() <= partial.upper());
+
+        // Another name in the second slot
+        let other = generate_inverse_partial(SENTENCE.replace("Fusco", "Lehet")).unwrap();
+        assert!(partial.matches(&other.lower()));
+        let other = generate_inverse_partial(SENTENCE.replace("Purdy", "Lehet")).unwrap();
+        assert!(!partial.matches(&other.lower()));
+    }
+
+    #[test]
+    fn test_partial_errors() {
+        assert_eq!(
+            generate_inverse_partial("Purdy Katharyn"),
+            Err(ParseError::UnknownWord {
+                position: 1,
+                category: crate::Category::Name
+            })
+        );
+        assert_eq!(
+            generate_inverse_partial("? ? ? the ? of ? ? ? ? ? and 99"),
+            Err(ParseError::NumberOutOfRange {
+                position: 12,
+                max: 31
+            })
+        );
+    }
+}
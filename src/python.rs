@@ -0,0 +1,318 @@
+//! Python module `uuid_readable` (requires the `python` feature), built with
+//! `maturin build`.
+//!
+//! ```python
+//! import uuid
+//! import uuid_readable
+//!
+//! sentence = uuid_readable.generate_from(uuid.UUID("0ee001c7-12f3-4b29-a4cc-f48838b3587a"))
+//! uuid_readable.generate_inverse(sentence)  # UUID('0ee001c7-12f3-4b29-a4cc-f48838b3587a')
+//! ```
+//!
+//! The UUIDs are given as `uuid.UUID` or as strings. A sentence that can't
+//! be decoded raises a subclass of `uuid_readable.ParseError`, itself a
+//! `ValueError`, whose attributes are the fields of the Rust error.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use pyo3::create_exception;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use uuid::Uuid;
+
+create_exception!(
+    uuid_readable,
+    ParseError,
+    PyValueError,
+    "The sentence can't be converted back."
+);
+create_exception!(
+    uuid_readable,
+    WordCountError,
+    ParseError,
+    "The sentence doesn't have the expected number of words."
+);
+create_exception!(
+    uuid_readable,
+    UnknownWordError,
+    ParseError,
+    "The word isn't part of the list used for its position."
+);
+create_exception!(
+    uuid_readable,
+    InvalidNumberError,
+    ParseError,
+    "The word should have been a number."
+);
+create_exception!(
+    uuid_readable,
+    NumberOutOfRangeError,
+    ParseError,
+    "The number is too big to fit in the bits of its position."
+);
+create_exception!(
+    uuid_readable,
+    ConnectorError,
+    ParseError,
+    "The word linking the parts of the sentence isn't the expected one."
+);
+create_exception!(
+    uuid_readable,
+    PaddingError,
+    ParseError,
+    "The padding is out of range or hides bytes that are not zero."
+);
+create_exception!(
+    uuid_readable,
+    ChecksumMismatchError,
+    ParseError,
+    "The checksum doesn't match the rest of the sentence."
+);
+create_exception!(
+    uuid_readable,
+    UnrecoverableError,
+    ParseError,
+    "Too many words are missing or wrong to rebuild the sentence."
+);
+create_exception!(
+    uuid_readable,
+    AmbiguousError,
+    ParseError,
+    "The words missing can be rebuilt in several ways, or a word only differs by its case from several ones of its list."
+);
+
+/// Create the exception `$type`, the variables `$attr` becoming its
+/// attributes of the same name
+macro_rules! exception {
+    ($py:expr, $type:ty, $message:expr $(, $attr:ident)*) => {{
+        let err = <$type>::new_err($message);
+        $(
+            if let Err(setattr) = err.value($py).setattr(stringify!($attr), $attr) {
+                return setattr;
+            }
+        )*
+        err
+    }};
+}
+
+/// Convert an error of the crate to its exception
+fn parse_error(py: Python<'_>, err: crate::ParseError) -> PyErr {
+    let message = err.to_string();
+    match err {
+        crate::ParseError::WordCount { expected, found } => {
+            exception!(py, WordCountError, message, expected, found)
+        }
+        crate::ParseError::UnknownWord { position, category } => {
+            let category = category.to_string();
+            exception!(py, UnknownWordError, message, position, category)
+        }
+        crate::ParseError::InvalidNumber { position } => {
+            exception!(py, InvalidNumberError, message, position)
+        }
+        crate::ParseError::NumberOutOfRange { position, max } => {
+            exception!(py, NumberOutOfRangeError, message, position, max)
+        }
+        crate::ParseError::Connector { position, expected } => {
+            exception!(py, ConnectorError, message, position, expected)
+        }
+        crate::ParseError::Padding { position } => {
+            exception!(py, PaddingError, message, position)
+        }
+        crate::ParseError::ChecksumMismatch => exception!(py, ChecksumMismatchError, message),
+        crate::ParseError::Unrecoverable => exception!(py, UnrecoverableError, message),
+        crate::ParseError::Ambiguous { candidates } => {
+            exception!(py, AmbiguousError, message, candidates)
+        }
+    }
+}
+
+/// A UUID given as a `uuid.UUID` or as a string
+#[derive(FromPyObject)]
+enum UuidLike {
+    Uuid(Uuid),
+    Str(String),
+}
+
+impl UuidLike {
+    fn parse(self) -> PyResult<Uuid> {
+        match self {
+            UuidLike::Uuid(uuid) => Ok(uuid),
+            UuidLike::Str(s) => {
+                Uuid::parse_str(&s).map_err(|err| PyValueError::new_err(err.to_string()))
+            }
+        }
+    }
+}
+
+/// Derive a long sentence from a UUID.
+#[pyfunction]
+fn generate_from(uuid: UuidLike) -> PyResult<String> {
+    Ok(crate::generate_from(uuid.parse()?))
+}
+
+/// Get the UUID a long sentence was created from.
+#[pyfunction]
+fn generate_inverse(py: Python<'_>, sentence: &str) -> PyResult<Uuid> {
+    crate::generate_inverse(sentence).map_err(|err| parse_error(py, err))
+}
+
+/// Derive a short sentence from a UUID.
+#[pyfunction]
+fn short_from(uuid: UuidLike) -> PyResult<String> {
+    Ok(crate::short_from(uuid.parse()?))
+}
+
+/// Parse each UUID of a list
+fn parse_many(uuids: Vec<UuidLike>) -> PyResult<Vec<Uuid>> {
+    uuids.into_iter().map(UuidLike::parse).collect()
+}
+
+/// Derive the long sentence of each UUID of a list.
+///
+/// The GIL is released during the conversion, as for the other `_many`
+/// functions.
+#[pyfunction]
+fn generate_from_many(py: Python<'_>, uuids: Vec<UuidLike>) -> PyResult<Vec<String>> {
+    let uuids = parse_many(uuids)?;
+    Ok(py.detach(|| crate::generate_many(&uuids)))
+}
+
+/// Get the UUID of each long sentence of a list. The exception raised for a
+/// sentence that can't be decoded has its position in the list as `index`.
+#[pyfunction]
+fn generate_inverse_many(py: Python<'_>, sentences: Vec<String>) -> PyResult<Vec<Uuid>> {
+    py.detach(|| crate::generate_inverse_many(&sentences))
+        .into_iter()
+        .enumerate()
+        .map(|(index, result)| {
+            result.map_err(|err| {
+                let err = parse_error(py, err);
+                match err.value(py).setattr("index", index) {
+                    Ok(()) => err,
+                    Err(setattr) => setattr,
+                }
+            })
+        })
+        .collect()
+}
+
+/// Derive the short sentence of each UUID of a list.
+#[pyfunction]
+fn short_from_many(py: Python<'_>, uuids: Vec<UuidLike>) -> PyResult<Vec<String>> {
+    let uuids = parse_many(uuids)?;
+    Ok(py.detach(|| uuids.into_iter().map(crate::short_from).collect()))
+}
+
+/// Easy to remember sentences acting as UUIDs.
+#[pymodule]
+fn uuid_readable(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let py = m.py();
+    m.add_function(wrap_pyfunction!(generate_from, m)?)?;
+    m.add_function(wrap_pyfunction!(generate_inverse, m)?)?;
+    m.add_function(wrap_pyfunction!(short_from, m)?)?;
+    m.add_function(wrap_pyfunction!(generate_from_many, m)?)?;
+    m.add_function(wrap_pyfunction!(generate_inverse_many, m)?)?;
+    m.add_function(wrap_pyfunction!(short_from_many, m)?)?;
+
+    m.add("ParseError", py.get_type::<ParseError>())?;
+    m.add("WordCountError", py.get_type::<WordCountError>())?;
+    m.add("UnknownWordError", py.get_type::<UnknownWordError>())?;
+    m.add("InvalidNumberError", py.get_type::<InvalidNumberError>())?;
+    m.add(
+        "NumberOutOfRangeError",
+        py.get_type::<NumberOutOfRangeError>(),
+    )?;
+    m.add("ConnectorError", py.get_type::<ConnectorError>())?;
+    m.add("PaddingError", py.get_type::<PaddingError>())?;
+    m.add(
+        "ChecksumMismatchError",
+        py.get_type::<ChecksumMismatchError>(),
+    )?;
+    m.add("UnrecoverableError", py.get_type::<UnrecoverableError>())?;
+    m.add("AmbiguousError", py.get_type::<AmbiguousError>())?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use pyo3::py_run;
+    use pyo3::types::PyModule;
+
+    use super::*;
+
+    /// Run Python code with the module bound to `uuid_readable`
+    fn run(code: &str) {
+        Python::initialize();
+        Python::attach(|py| {
+            let uuid_readable = PyModule::new(py, "uuid_readable").unwrap();
+            self::uuid_readable(&uuid_readable).unwrap();
+            py_run!(py, uuid_readable, code);
+        });
+    }
+
+    #[test]
+    fn test_python_roundtrip() {
+        run(r#"
+import uuid
+
+value = uuid.UUID("0ee001c7-12f3-4b29-a4cc-f48838b3587a")
+sentence = "Purdy Fusco Kask the loki of Manteo observed Barbe Lehet Pardew and 26 hard herons"
+assert uuid_readable.generate_from(value) == sentence
+assert uuid_readable.generate_from(str(value)) == sentence
+assert uuid_readable.generate_inverse(sentence) == value
+assert uuid_readable.short_from(value) == "Egidius filled by 0 calm hawks"
+
+assert uuid_readable.generate_from_many([value, uuid.UUID(int=0)]) == [
+    sentence,
+    "Fusco Fusco Fusco the muleteer of Katy suspended Fusco Fusco Fusco and 0 mysterious rooks",
+]
+assert uuid_readable.generate_inverse_many([sentence]) == [value]
+assert uuid_readable.short_from_many([]) == []
+"#);
+    }
+
+    #[test]
+    fn test_python_threads() {
+        run(r#"
+from concurrent.futures import ThreadPoolExecutor
+
+values = ["%032x" % (i * 0x9e3779b97f4a7c15f39cc0605cedc835 % (1 << 128)) for i in range(2000)]
+# Default arguments, the functions not seeing the variables of py_run
+def roundtrip(values, m=uuid_readable):
+    return [u.hex for u in m.generate_inverse_many(m.generate_from_many(values))]
+
+with ThreadPoolExecutor(4) as pool:
+    for result in pool.map(roundtrip, [values] * 4):
+        assert result == values
+"#);
+    }
+
+    #[test]
+    fn test_python_errors() {
+        run(r#"
+sentence = "Purdy Fusco Kask the loki of Manteo observed Barbe Lehet Pardew and 26 hard herons"
+try:
+    uuid_readable.generate_inverse(sentence.replace("Kask", "Kasky"))
+    assert False
+except uuid_readable.UnknownWordError as err:
+    assert isinstance(err, uuid_readable.ParseError)
+    assert isinstance(err, ValueError)
+    assert str(err) == "unknown name at position 2"
+    assert (err.position, err.category) == (2, "name")
+
+try:
+    uuid_readable.generate_inverse_many([sentence, "Purdy Fusco"])
+    assert False
+except uuid_readable.WordCountError as err:
+    assert (err.expected, err.found, err.index) == (15, 2, 1)
+
+try:
+    uuid_readable.generate_from("not a uuid")
+    assert False
+except ValueError as err:
+    assert not isinstance(err, uuid_readable.ParseError)
+"#);
+    }
+}
@@ -0,0 +1,190 @@
+use core::fmt;
+use core::str::FromStr;
+
+use uuid::Uuid;
+
+use crate::{generate_inverse, write_long, ParseError};
+
+/// A UUID displayed and parsed as its long sentence.
+///
+/// ```rust
+/// use uuid::Uuid;
+/// use uuid_readable_rs::ReadableUuid;
+///
+/// let uuid = Uuid::parse_str("0ee001c7-12f3-4b29-a4cc-f48838b3587a").unwrap();
+/// let readable = ReadableUuid::from(uuid);
+/// assert_eq!(
+///     readable.to_string(),
+///     "Purdy Fusco Kask the loki of Manteo observed Barbe Lehet Pardew and 26 hard herons"
+/// );
+///
+/// let parsed: ReadableUuid = readable.to_string().parse().unwrap();
+/// assert_eq!(Uuid::from(parsed), uuid);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct ReadableUuid(Uuid);
+
+impl ReadableUuid {
+    /// Create a new random UUID using the v4 RFC
+    #[cfg(feature = "random")]
+    pub fn new_v4() -> Self {
+        ReadableUuid(Uuid::new_v4())
+    }
+
+    /// Create a new time-ordered UUID using the v7 RFC
+    #[cfg(feature = "v7")]
+    pub fn now_v7() -> Self {
+        ReadableUuid(Uuid::now_v7())
+    }
+
+    /// Create the name-based UUID of `name` in `namespace` using the v5 RFC
+    #[cfg(feature = "v5")]
+    pub fn new_v5(namespace: &Uuid, name: &[u8]) -> Self {
+        ReadableUuid(Uuid::new_v5(namespace, name))
+    }
+
+    /// Get the underlying UUID
+    pub fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+
+    /// Consume self and return the underlying UUID
+    pub fn into_uuid(self) -> Uuid {
+        self.0
+    }
+}
+
+impl fmt::Display for ReadableUuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_long(&self.0, f)
+    }
+}
+
+impl FromStr for ReadableUuid {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        generate_inverse(s).map(ReadableUuid)
+    }
+}
+
+impl From<Uuid> for ReadableUuid {
+    fn from(uuid: Uuid) -> Self {
+        ReadableUuid(uuid)
+    }
+}
+
+impl From<ReadableUuid> for Uuid {
+    fn from(readable: ReadableUuid) -> Self {
+        readable.0
+    }
+}
+
+/// Conversions from the UUIDs of the 0.8 version of the `uuid` crate, for
+/// code which didn't move to the 1.x version yet.
+#[cfg(feature = "uuid08")]
+impl From<uuid08::Uuid> for ReadableUuid {
+    fn from(uuid: uuid08::Uuid) -> Self {
+        ReadableUuid(Uuid::from_bytes(*uuid.as_bytes()))
+    }
+}
+
+#[cfg(feature = "uuid08")]
+impl From<ReadableUuid> for uuid08::Uuid {
+    fn from(readable: ReadableUuid) -> Self {
+        uuid08::Uuid::from_bytes(*readable.0.as_bytes())
+    }
+}
+
+impl AsRef<Uuid> for ReadableUuid {
+    fn as_ref(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn test_display() {
+        let uuid = Uuid::parse_str("0ee001c7-12f3-4b29-a4cc-f48838b3587a").unwrap();
+        let readable = ReadableUuid::from(uuid);
+        assert_eq!(
+            format!("id={}", readable),
+            "id=Purdy Fusco Kask the loki of Manteo observed Barbe Lehet Pardew and 26 hard herons"
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        let uuid = Uuid::parse_str("0ee001c7-12f3-4b29-a4cc-f48838b3587a").unwrap();
+        let readable: ReadableUuid =
+            "Purdy Fusco Kask the loki of Manteo observed Barbe Lehet Pardew and 26 hard herons"
+                .parse()
+                .unwrap();
+        assert_eq!(readable.into_uuid(), uuid);
+
+        assert!("Purdy Fusco Kask".parse::<ReadableUuid>().is_err());
+    }
+
+    #[test]
+    fn test_ordering() {
+        let a = ReadableUuid::from(Uuid::nil());
+        let b = ReadableUuid::from(Uuid::from_bytes([0xff; 16]));
+        assert!(a < b);
+        assert_eq!(a, ReadableUuid::default());
+    }
+
+    #[cfg(feature = "v7")]
+    #[test]
+    fn test_now_v7() {
+        let a = ReadableUuid::now_v7();
+        let b = ReadableUuid::now_v7();
+        assert_eq!(a.as_uuid().get_version_num(), 7);
+        assert!(a < b);
+        // The order is kept once back from the sentences
+        let a: ReadableUuid = format!("{}", a).parse().unwrap();
+        let b: ReadableUuid = format!("{}", b).parse().unwrap();
+        assert!(a < b);
+    }
+
+    #[cfg(feature = "v5")]
+    #[test]
+    fn test_new_v5() {
+        let a = ReadableUuid::new_v5(&Uuid::NAMESPACE_DNS, b"example.com");
+        assert_eq!(a.as_uuid().get_version_num(), 5);
+        assert_eq!(
+            a,
+            ReadableUuid::new_v5(&Uuid::NAMESPACE_DNS, b"example.com")
+        );
+        assert_ne!(
+            a,
+            ReadableUuid::new_v5(&Uuid::NAMESPACE_DNS, b"example.org")
+        );
+    }
+
+    #[cfg(feature = "uuid08")]
+    #[test]
+    fn test_uuid08() {
+        let old = uuid08::Uuid::parse_str("0ee001c7-12f3-4b29-a4cc-f48838b3587a").unwrap();
+        let readable = ReadableUuid::from(old);
+        assert_eq!(
+            format!("{}", readable),
+            "Purdy Fusco Kask the loki of Manteo observed Barbe Lehet Pardew and 26 hard herons"
+        );
+        assert_eq!(uuid08::Uuid::from(readable), old);
+
+        // Given directly to the functions creating sentences
+        assert_eq!(crate::generate_from(old), format!("{}", readable));
+        assert_eq!(crate::short_from(old), "Egidius filled by 0 calm hawks");
+        assert!(crate::short_matches(old, "Egidius filled by 0 calm hawks"));
+        let sentence = crate::generate_checked_from(old);
+        assert_eq!(crate::generate_inverse_08(&sentence), Ok(old));
+        assert_eq!(
+            crate::Dictionary::builtin().generate_from(old),
+            format!("{}", readable)
+        );
+    }
+}
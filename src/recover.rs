@@ -0,0 +1,429 @@
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use uuid::Uuid;
+
+use crate::{
+    de_partition, partition, split_blanks, to_bits_parted, Category, Dictionary, ParseError, Part,
+    ReadableUuid, LONG_LAYOUT, NORMAL,
+};
+
+/// Bits of the symbols of the code, hence of each parity word
+const GF_BITS: u32 = 14;
+
+/// Primitive polynomial of GF(2^14): x^14 + x^10 + x^6 + x + 1
+const GF_POLY: u32 = 0x4443;
+
+/// Number of symbols of a codeword, the 12 parts of the long sentence
+/// followed by the 2 parity words
+const SYMBOLS: usize = 14;
+
+/// Most words that can be missing or unknown
+const MAX_ERASURES: usize = 2;
+
+/// Most words that can be left out, connectors included
+const MAX_OMITTED: usize = 4;
+
+/// Words following the long sentence, holding the parity
+const PARITY_LAYOUT: &[Part] = &[
+    Part::Literal(Cow::Borrowed("with")),
+    Part::Word {
+        category: Category::Name,
+        bits: GF_BITS as u8,
+    },
+    Part::Word {
+        category: Category::Name,
+        bits: GF_BITS as u8,
+    },
+];
+
+fn gf_mul(a: u16, b: u16) -> u16 {
+    let (mut a, mut b, mut r) = (a as u32, b, 0u32);
+    while b != 0 {
+        if b & 1 == 1 {
+            r ^= a;
+        }
+        b >>= 1;
+        a <<= 1;
+        if a & (1 << GF_BITS) != 0 {
+            a ^= GF_POLY;
+        }
+    }
+    r as u16
+}
+
+fn gf_pow(a: u16, mut n: u32) -> u16 {
+    let (mut base, mut r) = (a, 1);
+    while n != 0 {
+        if n & 1 == 1 {
+            r = gf_mul(r, base);
+        }
+        base = gf_mul(base, base);
+        n >>= 1;
+    }
+    r
+}
+
+fn gf_div(a: u16, b: u16) -> u16 {
+    // b^(2^14 - 2) is the inverse of b
+    gf_mul(a, gf_pow(b, (1 << GF_BITS) - 2))
+}
+
+/// The `i`-th power of the generator of GF(2^14)
+fn alpha(i: usize) -> u16 {
+    gf_pow(2, i as u32)
+}
+
+/// The two checks of the code: the sum of the symbols, and their sum
+/// weighted by the powers of alpha. Both are zero for a codeword.
+fn syndromes(symbols: &[u16; SYMBOLS]) -> (u16, u16) {
+    symbols.iter().enumerate().fold((0, 0), |(s0, s1), (i, c)| {
+        (s0 ^ c, s1 ^ gf_mul(*c, alpha(i)))
+    })
+}
+
+/// Compute the 2 parity symbols of the 12 parts of a UUID, a Reed-Solomon
+/// code able to fill 2 erased symbols or fix 1 wrong symbol.
+fn parity(data: &[u16]) -> (u16, u16) {
+    let mut symbols = [0; SYMBOLS];
+    symbols[..data.len()].copy_from_slice(data);
+    let (a, b) = syndromes(&symbols);
+
+    // p12 + p13 = a and p12 * alpha^12 + p13 * alpha^13 = b
+    let (x, y) = (alpha(SYMBOLS - 2), alpha(SYMBOLS - 1));
+    let p12 = gf_div(b ^ gf_mul(a, y), x ^ y);
+    (p12, a ^ p12)
+}
+
+/// Fill the `erased` symbols, or fix a single wrong one if none is erased.
+fn correct(symbols: &mut [u16; SYMBOLS], erased: &[usize]) -> bool {
+    let (s0, s1) = syndromes(symbols);
+    match *erased {
+        [] if s0 == 0 && s1 == 0 => true,
+        // A single wrong symbol at k gives s1 = s0 * alpha^k
+        [] if s0 != 0 => match (0..SYMBOLS).find(|&k| gf_mul(s0, alpha(k)) == s1) {
+            Some(k) => {
+                symbols[k] ^= s0;
+                true
+            }
+            None => false,
+        },
+        // The second check tells if the first one was enough
+        [k] if gf_mul(s0, alpha(k)) == s1 => {
+            symbols[k] = s0;
+            true
+        }
+        [j, k] => {
+            let (x, y) = (alpha(j), alpha(k));
+            let ek = gf_div(s1 ^ gf_mul(s0, x), x ^ y);
+            symbols[j] = s0 ^ ek;
+            symbols[k] = ek;
+            true
+        }
+        _ => false,
+    }
+}
+
+pub(crate) fn _generate_recoverable(dict: &Dictionary, uuid: &Uuid) -> String {
+    let data: Vec<u16> = partition(&NORMAL, uuid.as_bytes())
+        .into_iter()
+        .map(|v| v as u16)
+        .collect();
+    let (p0, p1) = parity(&data);
+
+    format!(
+        "{} with {} {}",
+        crate::_generate(dict, uuid),
+        dict.word(Category::Name, p0 as usize),
+        dict.word(Category::Name, p1 as usize)
+    )
+}
+
+/// Create a recoverable long sentence using a new random UUID, see
+/// `generate_recoverable_from`.
+#[cfg(feature = "random")]
+pub fn generate_recoverable() -> String {
+    _generate_recoverable(Dictionary::builtin(), &Uuid::new_v4())
+}
+
+/// Derive a long sentence from a UUID, followed by two names of parity from
+/// which `recover_inverse` can rebuild up to two forgotten words.
+///
+/// The parity is a Reed-Solomon code over the index of the 12 words of the
+/// sentence.
+///
+/// ```rust
+/// use uuid::Uuid;
+/// use uuid_readable_rs::{generate_recoverable_from, recover_inverse};
+///
+/// let uuid = Uuid::parse_str("0ee001c7-12f3-4b29-a4cc-f48838b3587a").unwrap();
+/// let sentence = generate_recoverable_from(uuid);
+/// assert_eq!(
+///     sentence,
+///     "Purdy Fusco Kask the loki of Manteo observed Barbe Lehet Pardew and 26 hard herons with Prior Chiquita"
+/// );
+///
+/// let forgotten = "Purdy ? Kask the loki of ? observed Barbe Lehet Pardew and 26 hard herons with Prior Chiquita";
+/// assert_eq!(recover_inverse(forgotten), Ok(uuid));
+/// ```
+pub fn generate_recoverable_from<U: Into<ReadableUuid>>(uuid: U) -> String {
+    _generate_recoverable(Dictionary::builtin(), uuid.into().as_uuid())
+}
+
+/// Get the original uuid from a sentence created by `generate_recoverable_from`,
+/// even if some of its words are missing.
+///
+/// Up to two words can be replaced by `?`, misspelled, or simply left out.
+/// A single wrong but valid word is corrected as well. The sentence is
+/// otherwise matched loosely, as with `generate_inverse`.
+///
+/// Words left out rather than replaced by `?` have to be placed back, which
+/// may give several UUIDs: this is reported as `ParseError::Ambiguous`.
+pub fn recover_inverse<S: AsRef<str>>(sentence: S) -> Result<Uuid, ParseError> {
+    _recover_inverse(Dictionary::builtin(), sentence.as_ref())
+}
+
+pub(crate) fn _recover_inverse(dict: &Dictionary, sentence: &str) -> Result<Uuid, ParseError> {
+    let layout: Vec<&Part> = LONG_LAYOUT.iter().chain(PARITY_LAYOUT.iter()).collect();
+    // The words of the sentence, `None` being a `?`
+    let words = split_blanks(sentence);
+
+    if words.len() > layout.len() || words.len() + MAX_OMITTED < layout.len() {
+        return Err(ParseError::WordCount {
+            expected: layout.len(),
+            found: words.len(),
+        });
+    }
+
+    // Try every way of placing the words left out back, keeping the ones
+    // with the fewest erased words as they were checked the most
+    let omitted = layout.len() - words.len();
+    let mut found: Vec<Uuid> = Vec::new();
+    let mut fewest = usize::MAX;
+    for mask in 0u32..1 << layout.len() {
+        if mask.count_ones() as usize != omitted {
+            continue;
+        }
+        if let Some((uuid, erased)) = align(dict, &layout, &words, mask) {
+            if erased < fewest {
+                fewest = erased;
+                found.clear();
+            }
+            if erased == fewest && !found.contains(&uuid) {
+                found.push(uuid);
+            }
+        }
+    }
+
+    match found.len() {
+        0 => Err(ParseError::Unrecoverable),
+        1 => Ok(found[0]),
+        candidates => Err(ParseError::Ambiguous { candidates }),
+    }
+}
+
+/// Decode the words placed in the layout, the bits of `omitted` being the
+/// positions left out. Give back the UUID along with the number of erased
+/// symbols.
+fn align(
+    dict: &Dictionary,
+    layout: &[&Part],
+    words: &[Option<&str>],
+    omitted: u32,
+) -> Option<(Uuid, usize)> {
+    let mut symbols = [0u16; SYMBOLS];
+    let mut erased = Vec::with_capacity(MAX_ERASURES);
+    let mut words = words.iter();
+    let mut symbol = 0;
+
+    for (position, part) in layout.iter().enumerate() {
+        let word = if omitted & 1 << position != 0 {
+            None
+        } else {
+            *words.next()?
+        };
+        let value = match (part, word) {
+            // The connectors are kept as anchors
+            (Part::Literal(literal), Some(word)) if word.eq_ignore_ascii_case(literal) => continue,
+            (Part::Literal(_), Some(_)) => return None,
+            (Part::Literal(_), None) if omitted & 1 << position != 0 => continue,
+            (Part::Literal(_), None) => return None,
+            (Part::Word { category, bits }, Some(word)) => {
+                match dict.lookup_any_case(*category, word) {
+                    Some(i) if i < 1 << bits => Some(i as u16),
+                    // A known word which can't be at this position
+                    Some(_) => return None,
+                    None if is_known(dict, layout, word) => return None,
+                    None => None,
+                }
+            }
+            (Part::Number { bits }, Some(word)) => match word.parse::<u16>() {
+                Ok(n) if u32::from(n) < 1 << bits => Some(n),
+                Ok(_) => return None,
+                Err(_) if is_known(dict, layout, word) => return None,
+                Err(_) => None,
+            },
+            (_, None) => None,
+        };
+
+        match value {
+            Some(value) => symbols[symbol] = value,
+            None => erased.push(symbol),
+        }
+        if erased.len() > MAX_ERASURES {
+            return None;
+        }
+        symbol += 1;
+    }
+
+    if !correct(&mut symbols, &erased) {
+        return None;
+    }
+    // The symbols filled must fit in their slot
+    let fits = NORMAL
+        .iter()
+        .zip(symbols.iter())
+        .all(|(bits, value)| u32::from(*value) < 1 << bits);
+    if !fits {
+        return None;
+    }
+
+    let bytes = de_partition(&to_bits_parted(&NORMAL, &symbols[..NORMAL.len()]));
+    let uuid = Uuid::from_slice(&bytes).expect("the long sentence holds 128 bits");
+    Some((uuid, erased.len()))
+}
+
+/// True if the word could be found at another position of the layout, in
+/// which case it wasn't misspelled but misplaced.
+fn is_known(dict: &Dictionary, layout: &[&Part], word: &str) -> bool {
+    layout.iter().any(|part| match part {
+        Part::Literal(literal) => word.eq_ignore_ascii_case(literal),
+        Part::Word { category, .. } => dict.lookup_any_case(*category, word).is_some(),
+        Part::Number { .. } => word.parse::<u16>().is_ok(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    const SENTENCE: &str = "Purdy Fusco Kask the loki of Manteo observed Barbe Lehet Pardew \
+                            and 26 hard herons with Prior Chiquita";
+
+    fn uuid() -> Uuid {
+        Uuid::parse_str("0ee001c7-12f3-4b29-a4cc-f48838b3587a").unwrap()
+    }
+
+    /// Positions of the words holding a symbol, the others being connectors
+    const SLOTS: [usize; SYMBOLS] = [0, 1, 2, 4, 6, 7, 8, 9, 10, 12, 13, 14, 16, 17];
+
+    fn replace(positions: &[usize], by: Option<&str>) -> String {
+        SENTENCE
+            .split(' ')
+            .enumerate()
+            .filter_map(|(i, w)| match by {
+                _ if !positions.contains(&i) => Some(w),
+                Some(by) => Some(by),
+                None => None,
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    #[test]
+    fn test_galois_field() {
+        // alpha generates every non-zero element
+        let mut seen = vec![false; 1 << GF_BITS];
+        let mut x = 1;
+        for _ in 0..(1 << GF_BITS) - 1 {
+            assert!(!seen[x as usize]);
+            seen[x as usize] = true;
+            x = gf_mul(x, 2);
+        }
+        assert_eq!(x, 1);
+        assert_eq!(gf_mul(gf_div(1234, 5678), 5678), 1234);
+    }
+
+    #[test]
+    fn test_recoverable() {
+        assert_eq!(generate_recoverable_from(uuid()), SENTENCE);
+        assert_eq!(recover_inverse(SENTENCE), Ok(uuid()));
+        assert_eq!(crate::generate_inverse(SENTENCE), Ok(uuid()));
+    }
+
+    #[cfg(feature = "random")]
+    #[test]
+    fn test_recoverable_random() {
+        for _ in 0..20 {
+            let sentence = generate_recoverable();
+            assert!(recover_inverse(&sentence).is_ok(), "{}", sentence);
+        }
+    }
+
+    #[test]
+    fn test_recover_forgotten() {
+        for (i, a) in SLOTS.iter().enumerate() {
+            assert_eq!(
+                recover_inverse(replace(&[*a], Some("?"))),
+                Ok(uuid()),
+                "{}",
+                a
+            );
+            assert_eq!(
+                recover_inverse(replace(&[*a], Some("xyz"))),
+                Ok(uuid()),
+                "{}",
+                a
+            );
+            assert_eq!(recover_inverse(replace(&[*a], None)), Ok(uuid()), "{}", a);
+            for b in SLOTS[i + 1..].iter() {
+                let sentence = replace(&[*a, *b], Some("?"));
+                assert_eq!(recover_inverse(&sentence), Ok(uuid()), "{}", sentence);
+            }
+        }
+    }
+
+    #[test]
+    fn test_recover_omitted() {
+        for pair in &[[0, 1], [6, 13], [12, 13], [16, 17]] {
+            assert_eq!(
+                recover_inverse(replace(pair, None)),
+                Ok(uuid()),
+                "{:?}",
+                pair
+            );
+        }
+        // Along with some connectors
+        assert_eq!(recover_inverse(replace(&[3, 5, 7], None)), Ok(uuid()));
+    }
+
+    #[test]
+    fn test_recover_wrong_word() {
+        // A valid name, but not the right one
+        assert_eq!(recover_inverse(replace(&[0], Some("Fusco"))), Ok(uuid()));
+        assert_eq!(recover_inverse(replace(&[12], Some("3"))), Ok(uuid()));
+    }
+
+    #[test]
+    fn test_unrecoverable() {
+        assert_eq!(
+            recover_inverse(replace(&[0, 6, 13], Some("?"))),
+            Err(ParseError::Unrecoverable)
+        );
+        assert_eq!(
+            recover_inverse("Purdy Fusco Kask"),
+            Err(ParseError::WordCount {
+                expected: 18,
+                found: 3
+            })
+        );
+        // Two adjacent names left out can be placed back in several ways
+        assert_eq!(
+            recover_inverse(replace(&[8, 9], None)),
+            Err(ParseError::Ambiguous { candidates: 2 })
+        );
+    }
+}
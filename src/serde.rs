@@ -0,0 +1,132 @@
+//! Serialize a `Uuid` as its long sentence (requires the `serde` feature).
+//!
+//! Deserializing accepts both the sentence and the usual textual forms of a
+//! UUID (hyphenated, simple, ...), making it easy to migrate existing data.
+//!
+//! ```rust
+//! use serde::{Deserialize, Serialize};
+//! use uuid::Uuid;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct User {
+//!     #[serde(with = "uuid_readable_rs::serde::long")]
+//!     id: Uuid,
+//! }
+//! ```
+
+use core::fmt;
+
+use ::serde::de::{self, Deserialize, Deserializer, Visitor};
+use ::serde::ser::{Serialize, Serializer};
+use uuid::Uuid;
+
+use crate::{generate_inverse, ReadableUuid};
+
+/// (De)serialize a `Uuid` as its long sentence, to be used with
+/// `#[serde(with = "uuid_readable_rs::serde::long")]`.
+pub mod long {
+    use super::*;
+
+    pub fn serialize<S>(uuid: &Uuid, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(&ReadableUuid::from(*uuid))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Uuid, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(UuidVisitor)
+    }
+}
+
+/// Accept either a sentence or a textual UUID
+struct UuidVisitor;
+
+impl<'de> Visitor<'de> for UuidVisitor {
+    type Value = Uuid;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a uuid-readable-rs sentence or a UUID string")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if let Ok(uuid) = Uuid::parse_str(v) {
+            return Ok(uuid);
+        }
+        generate_inverse(v).map_err(E::custom)
+    }
+}
+
+impl Serialize for ReadableUuid {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        long::serialize(self.as_uuid(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ReadableUuid {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        long::deserialize(deserializer).map(ReadableUuid::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::serde::{Deserialize, Serialize};
+    use alloc::string::ToString;
+    use uuid::Uuid;
+
+    use crate::ReadableUuid;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Event {
+        #[serde(with = "crate::serde::long")]
+        id: Uuid,
+        parent: ReadableUuid,
+    }
+
+    const JSON: &str = r#"{"id":"Purdy Fusco Kask the loki of Manteo observed Barbe Lehet Pardew and 26 hard herons","parent":"Fusco Fusco Fusco the muleteer of Katy suspended Fusco Fusco Fusco and 0 mysterious rooks"}"#;
+
+    fn event() -> Event {
+        Event {
+            id: Uuid::parse_str("0ee001c7-12f3-4b29-a4cc-f48838b3587a").unwrap(),
+            parent: ReadableUuid::from(Uuid::nil()),
+        }
+    }
+
+    #[test]
+    fn test_serialize() {
+        assert_eq!(serde_json::to_string(&event()).unwrap(), JSON);
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let event_back: Event = serde_json::from_str(JSON).unwrap();
+        assert_eq!(event_back, event());
+    }
+
+    #[test]
+    fn test_deserialize_hyphenated() {
+        let json = r#"{"id":"0ee001c7-12f3-4b29-a4cc-f48838b3587a","parent":"00000000-0000-0000-0000-000000000000"}"#;
+        let event_back: Event = serde_json::from_str(json).unwrap();
+        assert_eq!(event_back, event());
+    }
+
+    #[test]
+    fn test_deserialize_invalid() {
+        let json = r#"{"id":"Purdy Fusco Kask","parent":"Fusco"}"#;
+        let err = serde_json::from_str::<Event>(json).unwrap_err();
+        assert!(err.to_string().contains("expected 15 words, found 3"));
+    }
+}
@@ -0,0 +1,607 @@
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{
+    de_partition, is_punctuation, parse_number, partition, split_words, to_bits_parted, Category,
+    Dictionary, ParseError, TemplateError,
+};
+
+/// Largest number of bits a slot can hold, the values being read as `u16`
+const MAX_SLOT_BITS: u8 = 16;
+
+/// One word of a sentence template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Part {
+    /// A word linking the slots together, written as is
+    Literal(Cow<'static, str>),
+    /// A word of the list of `category`, holding `bits` bits
+    Word { category: Category, bits: u8 },
+    /// A number holding `bits` bits
+    Number { bits: u8 },
+}
+
+impl Part {
+    /// Number of bits held by the part, 0 for a literal
+    pub fn bits(&self) -> u8 {
+        match self {
+            Part::Literal(_) => 0,
+            Part::Word { bits, .. } | Part::Number { bits } => *bits,
+        }
+    }
+}
+
+/// The layout of a sentence, from which both the encoder and the parser are
+/// derived.
+///
+/// A template is written as words separated by a single space. Each slot is
+/// written as `{category:bits}`, the category being one of `name`,
+/// `personal_noun`, `place`, `verb`, `adjective`, `animal` or `number`. The
+/// bits of a word slot can be left out to use as many bits as its list can
+/// hold. Any other word is a literal written as is, which can't start or end
+/// with punctuation as it is dropped when decoding. The bits of the source
+/// are consumed by the slots in order, most significant first.
+///
+/// ```rust
+/// use uuid::Uuid;
+/// use uuid_readable_rs::Template;
+///
+/// let template = Template::new(
+///     "{adjective} {animal} from {place} {verb} {name} {name} {name} \
+///      and {number:11} {personal_noun} {name} of {place:10}",
+///     128,
+/// )
+/// .unwrap();
+///
+/// let uuid = Uuid::parse_str("0ee001c7-12f3-4b29-a4cc-f48838b3587a").unwrap();
+/// let sentence = template.encode_uuid(uuid);
+/// assert_eq!(template.decode_uuid(&sentence), Ok(uuid));
+/// ```
+#[derive(Clone)]
+pub struct Template<'a> {
+    parts: Cow<'static, [Part]>,
+    bits: u32,
+    dict: &'a Dictionary,
+}
+
+impl Template<'static> {
+    /// Parse a template using the builtin lists, its slots must hold
+    /// exactly `bits` bits, at most 128 as for a UUID.
+    pub fn new(template: &str, bits: u32) -> Result<Template<'static>, TemplateError> {
+        Template::with_dictionary(template, bits, Dictionary::builtin())
+    }
+}
+
+impl<'a> Template<'a> {
+    /// Parse a template drawing its words from `dict`, its slots must hold
+    /// exactly `bits` bits, at most 128 as for a UUID.
+    pub fn with_dictionary(
+        template: &str,
+        bits: u32,
+        dict: &'a Dictionary,
+    ) -> Result<Template<'a>, TemplateError> {
+        if bits > 128 {
+            return Err(TemplateError::TooManyBits(bits));
+        }
+
+        let parts = template
+            .split(' ')
+            .map(|word| parse_part(word, dict))
+            .collect::<Result<Vec<Part>, TemplateError>>()?;
+
+        for part in parts.iter() {
+            if let Part::Word { category, bits } = part {
+                let len = dict.list(*category).len();
+                if len < 1 << bits {
+                    return Err(TemplateError::ListTooSmall {
+                        category: *category,
+                        bits: *bits,
+                        len,
+                    });
+                }
+            }
+        }
+
+        let found = total_bits(&parts);
+        if found != bits {
+            return Err(TemplateError::BitBudget {
+                expected: bits,
+                found,
+            });
+        }
+
+        Ok(Template {
+            parts: Cow::Owned(parts),
+            bits,
+            dict,
+        })
+    }
+
+    /// Template of one of the builtin layouts, whose lists were checked
+    /// when building the dictionary.
+    pub(crate) fn builtin(parts: &'static [Part], dict: &'a Dictionary) -> Template<'a> {
+        Template {
+            parts: Cow::Borrowed(parts),
+            bits: total_bits(parts),
+            dict,
+        }
+    }
+
+    /// Number of bits held by the sentences
+    pub fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    /// The words of the template
+    pub fn parts(&self) -> &[Part] {
+        &self.parts
+    }
+
+    /// Create the sentence holding the first `bits()` bits of `bytes`.
+    ///
+    /// # Panics
+    /// If `bytes` is shorter than `bits()`.
+    pub fn encode(&self, bytes: &[u8]) -> String {
+        assert!(
+            bytes.len() * 8 >= self.bits as usize,
+            "the template needs {} bits, got {}",
+            self.bits,
+            bytes.len() * 8
+        );
+
+        let values = partition(&self.widths(), bytes);
+        let mut values = values.iter();
+        let mut sentence = String::new();
+        for (i, part) in self.parts.iter().enumerate() {
+            if i > 0 {
+                sentence.push(' ');
+            }
+            match part {
+                Part::Literal(word) => sentence.push_str(word),
+                Part::Word { category, .. } => {
+                    sentence.push_str(self.dict.word(*category, values.next().copied().unwrap()))
+                }
+                Part::Number { .. } => sentence.push_str(&values.next().unwrap().to_string()),
+            }
+        }
+
+        sentence
+    }
+
+    /// Get the bytes a sentence was created from, the bits past `bits()` of
+    /// the last byte being zeroed.
+    ///
+    /// The sentence is matched loosely, as with `generate_inverse`, but each
+    /// word must fit in the bits of its slot.
+    pub fn decode(&self, sentence: &str) -> Result<Vec<u8>, ParseError> {
+        self.decode_with(sentence, false, true)
+    }
+
+    /// Same as `decode`, but the sentence must be exactly the one created by
+    /// `encode`, as with `generate_inverse_strict`.
+    pub fn decode_strict(&self, sentence: &str) -> Result<Vec<u8>, ParseError> {
+        self.decode_with(sentence, true, true)
+    }
+
+    /// Create the sentence holding the first `bits()` bits of a UUID, which
+    /// is the whole UUID for a template of 128 bits.
+    pub fn encode_uuid(&self, uuid: uuid::Uuid) -> String {
+        let mut sentence = String::new();
+        self.write_uuid(&uuid, &mut sentence)
+            .expect("writing to a String can't fail");
+        sentence
+    }
+
+    /// Same as `encode_uuid`, but writing the sentence to `out`. Nothing is
+    /// allocated, the bits of each slot being shifted out of the UUID.
+    pub fn write_uuid<W: fmt::Write>(&self, uuid: &uuid::Uuid, out: &mut W) -> fmt::Result {
+        write_parts(
+            &self.parts,
+            uuid.as_u128(),
+            |c, i| self.dict.word(c, i),
+            out,
+        )
+    }
+
+    /// Get the UUID a sentence was created from, its bits past `bits()`
+    /// being zeroed.
+    pub fn decode_uuid(&self, sentence: &str) -> Result<uuid::Uuid, ParseError> {
+        let mut bytes = [0; 16];
+        for (b, d) in bytes.iter_mut().zip(self.decode(sentence)?) {
+            *b = d;
+        }
+
+        Ok(uuid::Uuid::from_bytes(bytes))
+    }
+
+    /// Parse a sentence, see `parse_values` for `exact` and `fit`
+    pub(crate) fn decode_with(
+        &self,
+        sentence: &str,
+        exact: bool,
+        fit: bool,
+    ) -> Result<Vec<u8>, ParseError> {
+        let values = self.parse_values(sentence, exact, fit)?;
+        Ok(self.assemble(&values))
+    }
+
+    /// Collect the value of each slot of a sentence.
+    ///
+    /// When `exact`, the words must be separated by a single space, match
+    /// their case, the literals must be the expected ones and the numbers
+    /// must be written as generated. Otherwise the literals and any trailing
+    /// words are ignored. When `fit`, the value of each slot must fit in its
+    /// bits, otherwise it will be truncated.
+    pub(crate) fn parse_values(
+        &self,
+        sentence: &str,
+        exact: bool,
+        fit: bool,
+    ) -> Result<Vec<u16>, ParseError> {
+        // Split the sentence
+        let splitted: Vec<&str> = if exact {
+            sentence.split(' ').collect()
+        } else {
+            split_words(sentence)
+        };
+        // Sanity check that we have enough values to work with
+        let expected = self.parts.len();
+        if splitted.len() < expected || (exact && splitted.len() > expected) {
+            return Err(ParseError::WordCount {
+                expected,
+                found: splitted.len(),
+            });
+        }
+
+        self.parse_words(&splitted, 0, exact, fit)
+    }
+
+    /// Collect the value of each slot from the words of a sentence, `offset`
+    /// being the position of the first word, see `parse_values`.
+    pub(crate) fn parse_words(
+        &self,
+        words: &[&str],
+        offset: usize,
+        exact: bool,
+        fit: bool,
+    ) -> Result<Vec<u16>, ParseError> {
+        let mut values = Vec::with_capacity(self.parts.len());
+        for (i, (part, &word)) in self.parts.iter().zip(words).enumerate() {
+            let position = offset + i;
+            match part {
+                Part::Literal(literal) => {
+                    if exact && word != literal {
+                        return Err(ParseError::Connector {
+                            position,
+                            expected: literal.to_string(),
+                        });
+                    }
+                }
+                Part::Word { category, bits } => {
+                    let index = if exact {
+                        self.dict.lookup(*category, word)
+                    } else {
+                        self.dict.lookup_any_case(*category, word)
+                    };
+                    let index = match index {
+                        Some(idx) if !fit || idx < 1 << bits => idx,
+                        None if !exact => {
+                            return Err(self.dict.unknown_word(*category, word, position))
+                        }
+                        _ => {
+                            return Err(ParseError::UnknownWord {
+                                position,
+                                category: *category,
+                            })
+                        }
+                    };
+                    values.push(index as u16);
+                }
+                Part::Number { bits } => {
+                    let number = parse_number(word, position)?;
+                    // Only accept the number as written by `encode`
+                    if exact && number.to_string() != word {
+                        return Err(ParseError::InvalidNumber { position });
+                    }
+                    if fit && u32::from(number) >= 1 << bits {
+                        return Err(ParseError::NumberOutOfRange {
+                            position,
+                            max: ((1u32 << bits) - 1) as u16,
+                        });
+                    }
+                    values.push(number);
+                }
+            }
+        }
+
+        Ok(values)
+    }
+
+    /// Convert the value of each slot back to bytes
+    pub(crate) fn assemble(&self, values: &[u16]) -> Vec<u8> {
+        de_partition(&to_bits_parted(&self.widths(), values))
+    }
+
+    /// Bits of each slot, in order
+    fn widths(&self) -> Vec<u8> {
+        self.parts
+            .iter()
+            .filter(|p| !matches!(p, Part::Literal(_)))
+            .map(Part::bits)
+            .collect()
+    }
+}
+
+impl fmt::Debug for Template<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Template")
+            .field("parts", &self.parts)
+            .field("bits", &self.bits)
+            .finish_non_exhaustive()
+    }
+}
+
+impl fmt::Display for Template<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, part) in self.parts.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            match part {
+                Part::Literal(word) => f.write_str(word)?,
+                Part::Word { category, bits } => {
+                    write!(f, "{{{}:{}}}", slot_name(*category), bits)?
+                }
+                Part::Number { bits } => write!(f, "{{number:{}}}", bits)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Write the sentence of `parts` holding the first bits of `value`, `word`
+/// giving the word at an index of a list.
+pub(crate) fn write_parts<'w, W, F>(
+    parts: &[Part],
+    value: u128,
+    word: F,
+    out: &mut W,
+) -> fmt::Result
+where
+    W: fmt::Write,
+    F: Fn(Category, usize) -> &'w str,
+{
+    // Bits of the value left after the current slot
+    let mut remaining = 128;
+    for (i, part) in parts.iter().enumerate() {
+        if i > 0 {
+            out.write_char(' ')?;
+        }
+        let bits = u32::from(part.bits());
+        remaining -= bits;
+        let index = || ((value >> remaining) & ((1 << bits) - 1)) as usize;
+        match part {
+            Part::Literal(literal) => out.write_str(literal)?,
+            Part::Word { category, .. } => out.write_str(word(*category, index()))?,
+            Part::Number { .. } => write!(out, "{}", index())?,
+        }
+    }
+
+    Ok(())
+}
+
+fn total_bits(parts: &[Part]) -> u32 {
+    parts.iter().map(|p| u32::from(p.bits())).sum()
+}
+
+/// Name of the category in a template
+fn slot_name(category: Category) -> &'static str {
+    match category {
+        Category::Name => "name",
+        Category::PersonalNoun => "personal_noun",
+        Category::Place => "place",
+        Category::Verb => "verb",
+        Category::Adjective => "adjective",
+        Category::Animal => "animal",
+    }
+}
+
+/// Parse a single word of a template
+fn parse_part(word: &str, dict: &Dictionary) -> Result<Part, TemplateError> {
+    let slot = match word.strip_prefix('{').and_then(|w| w.strip_suffix('}')) {
+        Some(slot) => slot,
+        // The punctuation around the words and any other whitespace are
+        // dropped when decoding, the literal would not be found back
+        None if word.is_empty()
+            || word.contains(['{', '}'])
+            || word.contains(char::is_whitespace)
+            || word.starts_with(is_punctuation)
+            || word.ends_with(is_punctuation) =>
+        {
+            return Err(TemplateError::InvalidWord(word.to_string()))
+        }
+        None => return Ok(Part::Literal(Cow::Owned(word.to_string()))),
+    };
+
+    let (name, bits) = match slot.split_once(':') {
+        Some((name, bits)) => match bits.parse::<u8>() {
+            Ok(bits) if bits > 0 && bits <= MAX_SLOT_BITS => (name, Some(bits)),
+            _ => return Err(TemplateError::InvalidBits(word.to_string())),
+        },
+        None => (slot, None),
+    };
+
+    if name == "number" {
+        return match bits {
+            Some(bits) => Ok(Part::Number { bits }),
+            None => Err(TemplateError::InvalidBits(word.to_string())),
+        };
+    }
+
+    let category = Category::ALL
+        .iter()
+        .copied()
+        .find(|c| slot_name(*c) == name)
+        .ok_or_else(|| TemplateError::UnknownSlot(word.to_string()))?;
+    // Use as many bits as the list can hold
+    let bits = bits.unwrap_or_else(|| {
+        let len = dict.list(category).len().max(1);
+        (usize::BITS - 1 - len.leading_zeros()).min(MAX_SLOT_BITS as u32) as u8
+    });
+
+    Ok(Part::Word { category, bits })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{format, vec};
+    use uuid::Uuid;
+
+    #[test]
+    fn test_builtin_templates() {
+        let dict = Dictionary::builtin();
+        assert_eq!(
+            dict.long_template().to_string(),
+            "{name:12} {name:11} {name:14} the {personal_noun:13} of {place:13} {verb:10} \
+             {name:12} {name:11} {name:14} and {number:5} {adjective:6} {animal:7}"
+        );
+        assert_eq!(dict.long_template().bits(), 128);
+        assert_eq!(
+            dict.short_template().to_string(),
+            "{name:6} {verb:6} by {number:7} {adjective:8} {animal:5}"
+        );
+        assert_eq!(dict.short_template().bits(), 32);
+
+        // Parsing the layout gives back the same template
+        let long = Template::new(&dict.long_template().to_string(), 128).unwrap();
+        assert_eq!(long.parts(), dict.long_template().parts());
+    }
+
+    #[test]
+    fn test_custom_template() {
+        let template = Template::new("{adjective} {animal} from {place} {verb} {name}", 52);
+        let template = template.unwrap();
+        assert_eq!(
+            template.to_string(),
+            "{adjective:8} {animal:7} from {place:13} {verb:10} {name:14}"
+        );
+
+        let uuid = Uuid::parse_str("0ee001c7-12f3-4b29-a4cc-f48838b3587a").unwrap();
+        let sentence = template.encode_uuid(uuid);
+        let bytes = template.decode_strict(&sentence).unwrap();
+        assert_eq!(bytes, vec![0x0e, 0xe0, 0x01, 0xc7, 0x12, 0xf3, 0x40]);
+        assert_eq!(template.decode(&sentence.to_uppercase()), Ok(bytes));
+
+        for uuid in &[Uuid::nil(), Uuid::from_bytes([0xff; 16])] {
+            let template = Template::new(
+                "{name} {name} {name} {name} {name} {name} {name} {name} {name:16}",
+                128,
+            );
+            assert!(template.is_err());
+
+            let template = Template::new(
+                "{name} {name} {name} {name} {name} {name} {name} {name} {number:16}",
+                128,
+            )
+            .unwrap();
+            let sentence = template.encode_uuid(*uuid);
+            assert_eq!(template.decode_uuid(&sentence), Ok(*uuid));
+            assert_eq!(
+                template.decode_strict(&sentence),
+                Ok(uuid.as_bytes().to_vec())
+            );
+        }
+    }
+
+    #[test]
+    fn test_template_parse_errors() {
+        let template = Template::new("the {animal:2} of {number:3}", 5).unwrap();
+        assert_eq!(
+            template.decode_strict("the weasels of 7"),
+            Ok(vec![0b0111_1000])
+        );
+        assert_eq!(
+            template.decode_strict("a weasels of 7"),
+            Err(ParseError::Connector {
+                position: 0,
+                expected: "the".to_string()
+            })
+        );
+        assert_eq!(
+            template.decode("the hogs of 7"),
+            Err(ParseError::UnknownWord {
+                position: 1,
+                category: Category::Animal
+            })
+        );
+        assert_eq!(
+            template.decode("the weasels of 8"),
+            Err(ParseError::NumberOutOfRange {
+                position: 3,
+                max: 7
+            })
+        );
+    }
+
+    #[test]
+    fn test_template_literals() {
+        // Punctuation inside a literal is kept
+        let template = Template::new("{name} o'clock {verb} well-known {place}", 37).unwrap();
+        let uuid = Uuid::parse_str("0ee001c7-12f3-4b29-a4cc-f48838b3587a").unwrap();
+        let sentence = template.encode_uuid(uuid);
+        assert!(sentence.contains(" o'clock "), "{}", sentence);
+        let bytes = template.decode(&sentence).unwrap();
+        assert_eq!(template.decode_strict(&sentence), Ok(bytes.clone()));
+        assert_eq!(template.encode(&bytes), sentence);
+    }
+
+    #[test]
+    fn test_invalid_template() {
+        assert_eq!(
+            Template::new("{name} {verb}", 128).unwrap_err(),
+            TemplateError::BitBudget {
+                expected: 128,
+                found: 24
+            }
+        );
+        // 9 names of 14 bits and a number of 14 bits
+        let template = format!("{} {{number:14}}", ["{name}"; 9].join(" "));
+        assert_eq!(
+            Template::new(&template, 140).unwrap_err(),
+            TemplateError::TooManyBits(140)
+        );
+        assert_eq!(
+            Template::new("{unicorn:3}", 3).unwrap_err(),
+            TemplateError::UnknownSlot("{unicorn:3}".to_string())
+        );
+        assert_eq!(
+            Template::new("{number}", 3).unwrap_err(),
+            TemplateError::InvalidBits("{number}".to_string())
+        );
+        assert_eq!(
+            Template::new("{number:17}", 17).unwrap_err(),
+            TemplateError::InvalidBits("{number:17}".to_string())
+        );
+        assert_eq!(
+            Template::new("{name}  {verb}", 24).unwrap_err(),
+            TemplateError::InvalidWord("".to_string())
+        );
+        for literal in &[",", "?", "*", "(by", "by.", "\tby"] {
+            let template = format!("{{name}} {} {{verb}}", literal);
+            assert_eq!(
+                Template::new(&template, 24).unwrap_err(),
+                TemplateError::InvalidWord(literal.to_string())
+            );
+        }
+        assert_eq!(
+            Template::new("{animal:8}", 8).unwrap_err(),
+            TemplateError::ListTooSmall {
+                category: Category::Animal,
+                bits: 8,
+                len: 128
+            }
+        );
+    }
+}
@@ -0,0 +1,96 @@
+//! JavaScript bindings (requires the `wasm` feature), for the npm package
+//! generated by `wasm-bindgen` from the crate built as a `cdylib`:
+//!
+//! ```sh
+//! cargo rustc --lib --release --target wasm32-unknown-unknown --features wasm --crate-type cdylib
+//! wasm-bindgen --target bundler --out-dir pkg target/wasm32-unknown-unknown/release/uuid_readable_rs.wasm
+//! ```
+//!
+//! ```js
+//! import { generateFrom, generateInverse } from "./pkg/uuid_readable_rs.js";
+//!
+//! const sentence = generateFrom("0ee001c7-12f3-4b29-a4cc-f48838b3587a");
+//! generateInverse(sentence); // "0ee001c7-12f3-4b29-a4cc-f48838b3587a"
+//! ```
+//!
+//! The errors are thrown as an `Error` holding the message of the Rust one.
+
+use alloc::string::{String, ToString};
+
+use uuid::Uuid;
+use wasm_bindgen::prelude::*;
+
+/// Parse a UUID given in any of its textual forms
+fn parse_uuid(uuid: &str) -> Result<Uuid, JsError> {
+    Uuid::parse_str(uuid).map_err(|err| JsError::new(&err.to_string()))
+}
+
+/// Create a long sentence using a new random UUID
+#[wasm_bindgen]
+pub fn generate() -> String {
+    crate::generate()
+}
+
+/// Derive a long sentence from a UUID
+#[wasm_bindgen(js_name = generateFrom)]
+pub fn generate_from(uuid: &str) -> Result<String, JsError> {
+    Ok(crate::generate_from(parse_uuid(uuid)?))
+}
+
+/// Get the hyphenated UUID a sentence was created from
+#[wasm_bindgen(js_name = generateInverse)]
+pub fn generate_inverse(sentence: &str) -> Result<String, JsError> {
+    crate::generate_inverse(sentence)
+        .map(|uuid| uuid.to_string())
+        .map_err(|err| JsError::new(&err.to_string()))
+}
+
+/// Derive a short sentence from a UUID
+#[wasm_bindgen(js_name = shortFrom)]
+pub fn short_from(uuid: &str) -> Result<String, JsError> {
+    Ok(crate::short_from(parse_uuid(uuid)?))
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use wasm_bindgen::JsValue;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use super::*;
+
+    const UUID: &str = "0ee001c7-12f3-4b29-a4cc-f48838b3587a";
+    const SENTENCE: &str =
+        "Purdy Fusco Kask the loki of Manteo observed Barbe Lehet Pardew and 26 hard herons";
+
+    /// Message of the JS exception thrown for an error
+    fn message(err: JsError) -> String {
+        let err = js_sys::Error::from(JsValue::from(err));
+        err.message().into()
+    }
+
+    #[wasm_bindgen_test]
+    fn test_generate_from() {
+        assert_eq!(generate_from(UUID).unwrap(), SENTENCE);
+        assert_eq!(short_from(UUID).unwrap(), "Egidius filled by 0 calm hawks");
+        assert_eq!(generate_inverse(SENTENCE).unwrap(), UUID);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_generate() {
+        let sentence = generate();
+        assert!(generate_inverse(&sentence).is_ok(), "{}", sentence);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_errors() {
+        assert_eq!(
+            message(generate_inverse("Purdy Fusco Kask").unwrap_err()),
+            "expected 15 words, found 3"
+        );
+        assert_eq!(
+            message(generate_inverse(&SENTENCE.replace("Purdy", "Purdyy")).unwrap_err()),
+            "unknown name at position 0"
+        );
+        assert!(generate_from("not a uuid").is_err());
+    }
+}
@@ -0,0 +1,116 @@
+//! Run the `uuid-readable` binary as a shell pipeline would.
+#![cfg(all(feature = "std", feature = "random"))]
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+const UUID: &str = "0ee001c7-12f3-4b29-a4cc-f48838b3587a";
+const SENTENCE: &str =
+    "Purdy Fusco Kask the loki of Manteo observed Barbe Lehet Pardew and 26 hard herons";
+
+/// Run the binary with `args`, writing `stdin` to its input
+fn run(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_uuid-readable"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> &str {
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+fn stderr(output: &Output) -> &str {
+    std::str::from_utf8(&output.stderr).unwrap()
+}
+
+#[test]
+fn test_encode_decode_args() {
+    let output = run(&["encode", UUID], "");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), format!("{}\n", SENTENCE));
+
+    let output = run(&["decode", SENTENCE], "");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), format!("{}\n", UUID));
+
+    // The words of the sentence given as separate arguments
+    let words: Vec<&str> = SENTENCE.split(' ').collect();
+    let output = run(&[&["decode"], &words[..]].concat(), "");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), format!("{}\n", UUID));
+}
+
+#[test]
+fn test_stdin_bad_line() {
+    let input = format!("{}\n\nnot a uuid\n{}\n", UUID, UUID);
+    let output = run(&["encode"], &input);
+    assert_eq!(output.status.code(), Some(1));
+    // The other lines are still converted
+    assert_eq!(stdout(&output), format!("{}\n{}\n", SENTENCE, SENTENCE));
+    assert!(
+        stderr(&output).starts_with("uuid-readable: line 3: invalid UUID"),
+        "{}",
+        stderr(&output)
+    );
+}
+
+#[test]
+fn test_usage_errors() {
+    let output = run(&["frobnicate"], "");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr(&output).contains("unknown command `frobnicate`"));
+    assert!(stdout(&output).is_empty());
+
+    assert_eq!(run(&[], "").status.code(), Some(2));
+    for count in &["0", "-1", "many"] {
+        let output = run(&["random", "--count", count], "");
+        assert_eq!(output.status.code(), Some(2), "{}", count);
+        assert!(stderr(&output).contains("--count expects a positive number"));
+    }
+}
+
+#[test]
+fn test_random_count() {
+    let output = run(&["random", "--count", "3"], "");
+    assert_eq!(output.status.code(), Some(0));
+    let sentences: Vec<&str> = stdout(&output).lines().collect();
+    assert_eq!(sentences.len(), 3);
+    for sentence in sentences {
+        assert_eq!(sentence.split(' ').count(), 15, "{}", sentence);
+    }
+
+    let output = run(&["random", "-n", "2", "--short"], "");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output).lines().count(), 2);
+}
+
+#[test]
+fn test_decode_strict() {
+    let output = run(&["decode", "--strict", SENTENCE], "");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), format!("{}\n", UUID));
+
+    // Accepted loosely, but not as generated
+    let lowered = SENTENCE.to_lowercase();
+    assert_eq!(run(&["decode", &lowered], "").status.code(), Some(0));
+    let output = run(&["decode", "--strict", &lowered], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stdout(&output).is_empty());
+    assert!(
+        stderr(&output).starts_with("uuid-readable: invalid sentence"),
+        "{}",
+        stderr(&output)
+    );
+}
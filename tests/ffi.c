@@ -0,0 +1,48 @@
+/* Use the C API as a C program would, run by tests/ffi.rs */
+#include <stdio.h>
+#include <string.h>
+
+#include "uuid_readable.h"
+
+#define CHECK(cond)                                                  \
+    do {                                                             \
+        if (!(cond)) {                                               \
+            fprintf(stderr, "%s:%d: %s\n", __FILE__, __LINE__, #cond); \
+            return 1;                                                \
+        }                                                            \
+    } while (0)
+
+static const uint8_t UUID[16] = {
+    0x0e, 0xe0, 0x01, 0xc7, 0x12, 0xf3, 0x4b, 0x29,
+    0xa4, 0xcc, 0xf4, 0x88, 0x38, 0xb3, 0x58, 0x7a,
+};
+static const char *SENTENCE =
+    "Purdy Fusco Kask the loki of Manteo observed Barbe Lehet Pardew and 26 hard herons";
+
+int main(void) {
+    char sentence[UUID_READABLE_LONG_LEN];
+    char short_sentence[UUID_READABLE_SHORT_LEN];
+    uint8_t uuid[16];
+    uint32_t prefix = 0;
+
+    CHECK(uuid_readable_generate_from(UUID, sentence, sizeof(sentence)) == UUID_READABLE_STATUS_OK);
+    CHECK(strcmp(sentence, SENTENCE) == 0);
+    CHECK(uuid_readable_generate_inverse(sentence, uuid) == UUID_READABLE_STATUS_OK);
+    CHECK(memcmp(uuid, UUID, 16) == 0);
+
+    CHECK(uuid_readable_short_from(UUID, short_sentence, sizeof(short_sentence)) == UUID_READABLE_STATUS_OK);
+    CHECK(strcmp(short_sentence, "Egidius filled by 0 calm hawks") == 0);
+    CHECK(uuid_readable_short_inverse(short_sentence, &prefix) == UUID_READABLE_STATUS_OK);
+    CHECK(prefix == 0x0ee001c7);
+
+    /* Errors */
+    CHECK(uuid_readable_generate_from(UUID, sentence, 10) == UUID_READABLE_STATUS_BUFFER_TOO_SMALL);
+    CHECK(sentence[0] == '\0');
+    CHECK(uuid_readable_generate_inverse("Purdy Fusco", uuid) == UUID_READABLE_STATUS_WORD_COUNT);
+    CHECK(uuid_readable_generate_inverse(NULL, uuid) == UUID_READABLE_STATUS_NULL_POINTER);
+    CHECK(strcmp(uuid_readable_status_message(UUID_READABLE_STATUS_WORD_COUNT), "wrong number of words") == 0);
+    CHECK(strcmp(uuid_readable_status_message(1000), "unknown status") == 0);
+    CHECK(strcmp(uuid_readable_status_message((uint32_t)-1), "unknown status") == 0);
+
+    return 0;
+}
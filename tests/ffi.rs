@@ -0,0 +1,97 @@
+//! Check the C header is up to date and use the C API from a C program.
+#![cfg(all(feature = "ffi", unix))]
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const HEADER: &str = "include/uuid_readable.h";
+
+fn generate_header() -> String {
+    let root = env!("CARGO_MANIFEST_DIR");
+    let config = cbindgen::Config::from_file(Path::new(root).join("cbindgen.toml")).unwrap();
+    let mut header = Vec::new();
+    cbindgen::Builder::new()
+        .with_config(config)
+        .with_src(Path::new(root).join("src/ffi.rs"))
+        .generate()
+        .unwrap()
+        .write(&mut header);
+
+    String::from_utf8(header).unwrap()
+}
+
+#[test]
+fn test_header() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(HEADER);
+    let header = generate_header();
+    if env::var_os("UUID_READABLE_UPDATE_HEADER").is_some() {
+        fs::write(&path, header).unwrap();
+        return;
+    }
+
+    assert!(
+        fs::read_to_string(&path).unwrap() == header,
+        "{} is outdated, regenerate it with `UUID_READABLE_UPDATE_HEADER=1 cargo test --features ffi --test ffi`",
+        HEADER
+    );
+}
+
+/// Build the crate as a `cdylib`, as a C program would link it, returning
+/// the directory holding it
+fn build_cdylib() -> PathBuf {
+    let target_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("cdylib");
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let status = Command::new(cargo)
+        .args([
+            "rustc",
+            "--lib",
+            "--features",
+            "ffi",
+            "--crate-type",
+            "cdylib",
+        ])
+        .arg("--manifest-path")
+        .arg(Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml"))
+        // Apart from the target directory of the running `cargo test`
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .status()
+        .unwrap();
+    assert!(status.success(), "failed to build the cdylib");
+
+    target_dir.join("debug")
+}
+
+#[test]
+fn test_c_program() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let lib_dir = build_cdylib();
+    let program = Path::new(env!("CARGO_TARGET_TMPDIR")).join("ffi");
+
+    let compiler = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+    let status = Command::new(compiler)
+        .arg(root.join("tests/ffi.c"))
+        .arg("-I")
+        .arg(root.join("include"))
+        .arg("-L")
+        .arg(&lib_dir)
+        .arg("-luuid_readable_rs")
+        .arg("-o")
+        .arg(&program)
+        .status()
+        .unwrap();
+    assert!(status.success(), "failed to compile tests/ffi.c");
+
+    let output = Command::new(&program)
+        .env("LD_LIBRARY_PATH", &lib_dir)
+        .env("DYLD_LIBRARY_PATH", &lib_dir)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}